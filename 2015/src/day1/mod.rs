@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 1,
     name: "Day 1: Not Quite Lisp",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 10,
     name: "Day 10: Elves Look, Elves Say",
     input: std::include_bytes!("input"),
     solve: self::solve,
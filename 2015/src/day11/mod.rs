@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 11,
     name: "Day 11: Corporate Policy",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -97,6 +99,7 @@ pub fn solve(input: &[u8]) -> (String, String) {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod tests {
     use super::*;
     use common::{example, solution};
@@ -1,6 +1,8 @@
 use serde_json::Value;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 12,
     name: "Day 12: JSAbacusFramework.io",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 13,
     name: "Day 13: Knights of the Dinner Table",
     input: std::include_bytes!("input"),
     solve: self::solve,
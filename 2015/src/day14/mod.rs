@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 14,
     name: "Day 14: Reindeer Olympics",
     input: std::include_bytes!("input"),
     solve: self::solve,
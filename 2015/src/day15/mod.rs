@@ -3,6 +3,8 @@ use std::ops::Range;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 15,
     name: "Day 15: Science for Hungry People",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 16,
     name: "Day 16: Aunt Sue",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 17,
     name: "Day 17: No Such Thing as Too Much",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,226 +1,58 @@
-use std::{
-    fmt::Display,
-    mem,
-    ops::{Index, IndexMut},
-};
+use std::mem;
+
+use common::grid::Grid;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 18,
     name: "Day 18: Like a GIF For Your Yard",
     input: std::include_bytes!("input"),
     solve: self::solve,
 };
 
-#[derive(Debug, Clone)]
-struct Grid {
-    width: usize,
-    height: usize,
-    cells: Vec<bool>,
-}
-
-fn str_to_grid(s: &str) -> Grid {
-    let mut width = 0;
-    let mut height = 0;
-    let mut cells = Vec::new();
-
-    let mut first = true;
-    for (i, c) in s.chars().enumerate() {
-        match c {
-            '\n' => {
-                if first {
-                    first = false;
-                    width = i;
-                    height += 1;
-                } else {
-                    if (i + 1) % (width + 1) != 0 {
-                        panic!("lines at different width");
-                    }
-                    height += 1;
-                }
-            }
-            '#' => cells.push(true),
-            '.' => cells.push(false),
-            c => panic!("illegal character: {}", c),
-        }
-    }
-
-    Grid {
-        width,
-        height,
-        cells,
-    }
-}
-
-impl Grid {
-    /// Returns the number of neighbouring cells that are currently lit.
-    fn num_neighbours(&self, x: usize, y: usize) -> usize {
-        if x >= self.width || y >= self.height {
-            panic!(
-                "index exceeded width or height: x={}, y={}, w={}, h={}",
-                x, y, self.width, self.height
-            );
-        }
-
-        const TOP_L: usize = 0;
-        const TOP_M: usize = 1;
-        const TOP_R: usize = 2;
-        const MID_L: usize = 3;
-        const MID_R: usize = 4;
-        const BOT_L: usize = 5;
-        const BOT_M: usize = 6;
-        const BOT_R: usize = 7;
-
-        let mut count = 0;
-        let mut check = [true; 8];
-
-        if x == 0 {
-            check[TOP_L] = false;
-            check[MID_L] = false;
-            check[BOT_L] = false;
-        }
-        if y == 0 {
-            check[TOP_L] = false;
-            check[TOP_M] = false;
-            check[TOP_R] = false;
-        }
-        if x == self.width - 1 {
-            check[TOP_R] = false;
-            check[MID_R] = false;
-            check[BOT_R] = false;
-        }
-        if y == self.height - 1 {
-            check[BOT_L] = false;
-            check[BOT_M] = false;
-            check[BOT_R] = false;
-        }
-
-        if check[TOP_L] && self.cells[self.width * (y - 1) + (x - 1)] {
-            count += 1;
-        }
-        if check[TOP_M] && self.cells[self.width * (y - 1) + x] {
-            count += 1;
-        }
-        if check[TOP_R] && self.cells[self.width * (y - 1) + (x + 1)] {
-            count += 1;
-        }
-        if check[MID_L] && self.cells[self.width * y + (x - 1)] {
-            count += 1;
-        }
-        if check[MID_R] && self.cells[self.width * y + (x + 1)] {
-            count += 1;
-        }
-        if check[BOT_L] && self.cells[self.width * (y + 1) + (x - 1)] {
-            count += 1;
-        }
-        if check[BOT_M] && self.cells[self.width * (y + 1) + x] {
-            count += 1;
-        }
-        if check[BOT_R] && self.cells[self.width * (y + 1) + (x + 1)] {
-            count += 1;
-        }
-
-        count
-    }
-
-    fn iter(&self) -> GridIter<'_> {
-        GridIter {
-            grid: self,
-            idx: 0,
-            len: self.height * self.width,
-        }
-    }
-}
-
-impl Index<usize> for Grid {
-    type Output = bool;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.cells[index]
-    }
-}
-
-impl IndexMut<usize> for Grid {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.cells[index]
-    }
-}
-
-impl Display for Grid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let c = if self[self.width * y + x] { '#' } else { '.' };
-                write!(f, "{}", c)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-struct GridIter<'a> {
-    grid: &'a Grid,
-    idx: usize,
-    len: usize,
-}
-
-impl Iterator for GridIter<'_> {
-    type Item = bool;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let item: Option<Self::Item>;
-
-        if self.idx < self.len {
-            item = Some(self.grid[self.idx]);
-            self.idx += 1;
-        } else {
-            item = None;
-        }
-
-        item
-    }
+/// Number of lit lights around `(x, y)`.
+fn lit_neighbours(grid: &Grid<bool>, x: usize, y: usize) -> usize {
+    grid.neighbours8(x, y).filter(|&pos| grid[pos]).count()
 }
 
 /// Takes the state of the grid in `src` and steps forward one step and writes
 /// the resulting state to `dst`.
-fn step(dst: &mut Grid, src: &Grid) {
-    for y in 0..src.height {
-        for x in 0..src.width {
-            let n = src.num_neighbours(x, y);
-            let idx = dst.width * y + x;
-
-            if src[idx] {
-                dst[idx] = n == 2 || n == 3;
-            } else {
-                dst[idx] = n == 3;
-            }
-        }
+fn step(dst: &mut Grid<bool>, src: &Grid<bool>) {
+    for ((x, y), lit) in dst.iter_mut() {
+        let n = lit_neighbours(src, x, y);
+        *lit = if src[(x, y)] {
+            n == 2 || n == 3
+        } else {
+            n == 3
+        };
     }
 }
 
 /// Same as [`step`], but leaves all corners as is.
-fn step_ignore_corners(dst: &mut Grid, src: &Grid) {
-    for y in 0..src.height {
-        let range = if y == 0 || y == src.height - 1 {
-            1..src.width - 1
-        } else {
-            0..src.width
-        };
+fn step_ignore_corners(dst: &mut Grid<bool>, src: &Grid<bool>) {
+    let (width, height) = (src.width(), src.height());
+    let is_corner = |x: usize, y: usize| (x == 0 || x == width - 1) && (y == 0 || y == height - 1);
 
-        for x in range {
-            let n = src.num_neighbours(x, y);
-            let idx = dst.width * y + x;
-
-            if src[idx] {
-                dst[idx] = n == 2 || n == 3;
-            } else {
-                dst[idx] = n == 3;
-            }
+    for ((x, y), lit) in dst.iter_mut() {
+        if is_corner(x, y) {
+            continue;
         }
+        let n = lit_neighbours(src, x, y);
+        *lit = if src[(x, y)] {
+            n == 2 || n == 3
+        } else {
+            n == 3
+        };
     }
 }
 
 fn solve_(input: &str, iterations: usize, ignore_corners: bool) -> usize {
-    let mut src = str_to_grid(input);
+    let mut src = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .unwrap_or_else(|err| panic!("invalid grid: {err}"));
     let mut dst = src.clone();
 
     if ignore_corners {
@@ -235,7 +67,7 @@ fn solve_(input: &str, iterations: usize, ignore_corners: bool) -> usize {
         }
     }
 
-    src.iter().filter(|&x| x).count()
+    src.iter().filter(|(_, lit)| **lit).count()
 }
 
 pub fn solve(input: &[u8]) -> (String, String) {
@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 2,
     name: "Day 2: I Was Told There Would Be No Math",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 20,
     name: "Day 20: Infinite Elves and Infinite Houses",
     input: std::include_bytes!("input"),
     solve: self::solve,
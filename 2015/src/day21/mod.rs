@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 21,
     name: "Day 21: RPG Simulator 20XX",
     input: std::include_bytes!("input"),
     solve: self::solve,
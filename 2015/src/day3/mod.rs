@@ -8,6 +8,8 @@ struct House {
 }
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 3,
     name: "Day 3: Perfectly Spherical Houses in a Vacuum",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,9 +1,13 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 4,
     name: "Day 4: The Ideal Stocking Stuffer",
     input: std::include_bytes!("input"),
     solve: self::solve,
 };
 
+use common_v2::progress;
+
 pub fn solve(input: &[u8]) -> (String, String) {
     let input = String::from_utf8_lossy(input).into_owned();
     let mut hash_input = input.clone();
@@ -13,6 +17,11 @@ pub fn solve(input: &[u8]) -> (String, String) {
 
     // Part 1.
     let five_zeroes: u32 = loop {
+        // Only now and then, as reading the clock for every hash slows it
+        // down.
+        if i.is_multiple_of(100_000) {
+            progress::status(format_args!("part 1, trying {i}"));
+        }
         hash_input.push_str(&i.to_string());
         let digest = md5::compute(hash_input.as_bytes());
 
@@ -29,6 +38,9 @@ pub fn solve(input: &[u8]) -> (String, String) {
 
     // Part 2.
     let six_zeroes: u32 = loop {
+        if i.is_multiple_of(100_000) {
+            progress::status(format_args!("part 2, trying {i}"));
+        }
         hash_input.push_str(&i.to_string());
         let digest = md5::compute(hash_input.as_bytes());
 
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 5,
     name: "Day 5: Doesn't He Have Intern-Elves For This?",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 6,
     name: "Day 6: Probably a Fire Hazard",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::day7::tokens::BinaryOp;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 7,
     name: "Day 7: Some Assembly Required",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -415,12 +417,16 @@ pub fn solve(input: &[u8]) -> (String, String) {
         statement: &parser::Statement,
         statements: &HashMap<String, parser::Statement>,
         wires: &mut HashMap<String, u16>,
+        evaluating: &mut HashSet<String>,
     ) {
         let parser::Statement(expr, ident) = statement;
 
         if wires.get(ident).is_some() {
             return;
         }
+        if !evaluating.insert(ident.clone()) {
+            panic!("circuit has a loop through wire {}", &ident);
+        }
 
         let signal = {
             let mut get_signal = |value: &parser::Value| -> u16 {
@@ -432,7 +438,7 @@ pub fn solve(input: &[u8]) -> (String, String) {
                         let Some(s) = statements.get(ident) else {
                             panic!("no statements evaluates to {}", &ident)
                         };
-                        eval_statement(s, statements, wires);
+                        eval_statement(s, statements, wires, evaluating);
                         *wires.get(ident).unwrap()
                     }
                     parser::Value::Signal(signal) => *signal,
@@ -455,28 +461,31 @@ pub fn solve(input: &[u8]) -> (String, String) {
                     BinaryOp::LShift => {
                         let left = get_signal(left);
                         let right = get_signal(right);
-                        left << right
+                        left.checked_shl(right.into()).unwrap_or(0)
                     }
                     BinaryOp::RShift => {
                         let left = get_signal(left);
                         let right = get_signal(right);
-                        left >> right
+                        left.checked_shr(right.into()).unwrap_or(0)
                     }
                 },
             }
         };
+        evaluating.remove(ident);
         wires.insert(ident.clone(), signal);
     }
 
     // Part 1
-    let s = wire_statements.get("a").unwrap();
-    eval_statement(s, &wire_statements, &mut wires);
+    let s = wire_statements
+        .get("a")
+        .expect("no statements evaluates to a");
+    eval_statement(s, &wire_statements, &mut wires, &mut HashSet::new());
     let part1 = *wires.get("a").unwrap();
 
     // Part 2
     wires.clear();
     wires.insert("b".to_owned(), part1);
-    eval_statement(s, &wire_statements, &mut wires);
+    eval_statement(s, &wire_statements, &mut wires, &mut HashSet::new());
     let part2 = *wires.get("a").unwrap();
 
     // // Print all wires
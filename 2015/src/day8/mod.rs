@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 8,
     name: "Day 8: Matchsticks",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2015,
+    day: 9,
     name: "Day 9: All in a Single Night",
     input: std::include_bytes!("input"),
     solve: self::solve,
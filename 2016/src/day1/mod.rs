@@ -3,6 +3,8 @@ use std::collections::HashSet;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2016,
+    day: 1,
     name: "Day 1: No Time for a Taxicab",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -3,6 +3,8 @@ mod keypad;
 use keypad::*;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2016,
+    day: 2,
     name: "Day 2: Bathroom Security",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2016,
+    day: 3,
     name: "Day 3: Squares With Three Sides",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -2,30 +2,14 @@ pub mod day1;
 pub mod day2;
 pub mod day3;
 
-pub const SOLUTIONS: &[Option<common::Solution>] = &[
-    Some(day1::SOLUTION),
-    Some(day2::SOLUTION),
-    Some(day3::SOLUTION),
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-    None,
-];
+pub const SOLUTIONS: &[common::Solution] = &[day1::SOLUTION, day2::SOLUTION, day3::SOLUTION];
+
+const _: () = common::check_solutions(2016, SOLUTIONS);
+
+#[cfg(test)]
+mod examples {
+    #[allow(unused_imports)]
+    use common::{example_file, fuzz_file};
+
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
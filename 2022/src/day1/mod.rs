@@ -1,6 +1,8 @@
 use std::{cmp::Reverse, collections::BinaryHeap};
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 1,
     name: "Day 1: Calorie Counting",
     input: std::include_bytes!("input"),
     solve: self::solve,
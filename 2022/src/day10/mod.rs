@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 10,
     name: "Day 10: Cathode-Ray Tube",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -443,4 +445,5 @@ noop",
 #....#....#..#.#....#....#....#..#.#....
 #....####..##..####.####.####.#..#.####."
     );
+    solution!(p2, p2_solution_letters, "FECZELHE");
 }
@@ -4,6 +4,8 @@ use num::Integer;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 11,
     name: "Day 11: Monkey in the Middle",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 12,
     name: "Day 12: Hill Climbing Algorithm",
     input: std::include_bytes!("input"),
     solve: self::solve,
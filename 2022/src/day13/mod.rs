@@ -1,6 +1,8 @@
 mod data;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 13,
     name: "Day 13: Distress Signal",
     input: std::include_bytes!("input"),
     solve: self::solve,
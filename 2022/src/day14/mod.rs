@@ -3,9 +3,12 @@ mod sand;
 
 use std::collections::HashMap;
 
+use common_v2::visual;
 use sand::Tile;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 14,
     name: "Day 14: Regolith Reservoir",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -52,18 +55,22 @@ pub fn solve(input: &[u8]) -> (String, String) {
     );
 
     // Part 1.
-    while world.add_sand_grain().y < world.floor() - 1 {}
+    while world.add_sand_grain().y < world.floor() - 1 {
+        if world.count().is_multiple_of(5) {
+            visual::frame(world.display_colour());
+        }
+    }
     let part1 = world.count() - 1;
-
-    // // Uncomment to print out world.
-    // println!("{}", world);
+    visual::frame(world.display_colour());
 
     // Part 2.
-    while world.add_sand_grain() != world.source() {}
+    while world.add_sand_grain() != world.source() {
+        if world.count().is_multiple_of(250) {
+            visual::frame(world.display_colour());
+        }
+    }
     let part2 = world.count();
-
-    // // Uncomment to print out world using ANSI colours.
-    // println!("{}", world.display_colour());
+    visual::frame(world.display_colour());
 
     (part1.to_string(), part2.to_string())
 }
@@ -104,7 +104,6 @@ impl World {
         grain
     }
 
-    #[allow(dead_code)]
     pub fn display_colour(&self) -> WorldDisplayColour<'_> {
         WorldDisplayColour { world: self }
     }
@@ -136,40 +135,36 @@ pub struct WorldDisplayColour<'a> {
 
 impl Display for WorldDisplayColour<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (left, right) = (self.world.left(), self.world.right());
         for y in self.world.source.y..=self.world.floor {
-            for x in self.world.left()..=self.world.right() {
+            // Colours are only written when they change, to keep it short.
+            let mut colours = None;
+            for x in left..=right {
                 let tile = if y == self.world.floor() {
                     Some(&Tile::Rock)
                 } else {
                     self.world.tiles.get(&Loc::from((x, y)))
                 };
 
-                if let Some(tile) = tile {
-                    match tile {
-                        Tile::Rock => {
-                            SetForegroundColor(Color::Black).write_ansi(f)?;
-                            SetBackgroundColor(Color::DarkGrey).write_ansi(f)?;
-                            write!(f, " ")?;
-                        }
-                        Tile::Sand => {
-                            SetForegroundColor(Color::Black).write_ansi(f)?;
-                            SetBackgroundColor(Color::Yellow).write_ansi(f)?;
-                            write!(f, " ")?;
-                        }
+                let (foreground, background, c) = match tile {
+                    Some(Tile::Rock) => (Color::Black, Color::DarkGrey, ' '),
+                    Some(Tile::Sand) => (Color::Black, Color::Yellow, ' '),
+                    None if Loc::from((x, y)) == self.world.source() => {
+                        (Color::Black, Color::Red, '+')
                     }
-                } else if Loc::from((x, y)) == self.world.source() {
-                    SetForegroundColor(Color::Black).write_ansi(f)?;
-                    SetBackgroundColor(Color::Red).write_ansi(f)?;
-                    write!(f, "+")?;
-                } else {
-                    SetForegroundColor(Color::DarkGrey).write_ansi(f)?;
-                    SetBackgroundColor(Color::Reset).write_ansi(f)?;
-                    write!(f, ".")?;
+                    None => (Color::DarkGrey, Color::Reset, '.'),
+                };
+
+                if colours != Some((foreground, background)) {
+                    SetForegroundColor(foreground).write_ansi(f)?;
+                    SetBackgroundColor(background).write_ansi(f)?;
+                    colours = Some((foreground, background));
                 }
+                write!(f, "{c}")?;
             }
+            ResetColor.write_ansi(f)?;
             writeln!(f)?;
         }
-        ResetColor.write_ansi(f)?;
         Ok(())
     }
 }
@@ -6,6 +6,8 @@ mod parse;
 use data::Tile;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 15,
     name: "Day 15: Beacon Exclusion Zone",
     input: std::include_bytes!("input"),
     solve: self::solve,
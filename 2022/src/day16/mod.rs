@@ -10,6 +10,8 @@ use std::collections::{HashMap, VecDeque};
 use data::*;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 16,
     name: "Day 16: Proboscidea Volcanium",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,10 +1,11 @@
 mod data;
 
-use std::collections::HashMap;
-
+use common::cycle;
 use data::*;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 17,
     name: "Day 17: Pyroclastic Flow",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -23,39 +24,23 @@ pub fn solve(input: &[u8]) -> (String, String) {
         _ => panic!("illegal character '{}' found", c),
     });
 
-    let mut world = World::new(wind, 0, 6, 0);
-    let mut states = HashMap::new();
-
     // Part 1.
-    for i in 0..PART1_NUM_ITERATIONS {
-        let state = world.drop_rock();
-
-        states.insert(state, (i, world.top())); // For part2.
+    let mut world = World::new(wind.clone(), 0, 6, 0);
+    for _ in 0..PART1_NUM_ITERATIONS {
+        world.drop_rock();
     }
     let part1 = world.top();
 
     // Part 2.
-    let mut shortcut_height = 0;
-    for i in PART1_NUM_ITERATIONS..PART2_NUM_ITERATIONS {
-        let state = world.drop_rock();
-
-        // Record new state and check if it has been seen before.
-        // If it has been seen then that indicates a likely cycle and we
-        // can use that as a shortcut to the solution.
-        if let Some((old_i, old_top)) = states.insert(state, (i, world.top())) {
-            let iter_left = PART2_NUM_ITERATIONS - (i + 1);
-            let cycle_len = i - old_i;
-            let n_cycles_left = iter_left / cycle_len;
-            shortcut_height = n_cycles_left as i64 * (world.top() - old_top);
-
-            for _ in (i + n_cycles_left * cycle_len + 1)..PART2_NUM_ITERATIONS {
-                world.drop_rock();
-            }
-
-            break;
-        }
-    }
-    let part2 = world.top() + shortcut_height;
+    // The state returned by dropping a rock is used as key, and the height of
+    // the tower is the value that grows with every cycle.
+    let (cycle, heights) = cycle::find(
+        &mut (World::new(wind, 0, 6, 0), None),
+        |(world, state)| *state = Some(world.drop_rock()),
+        |(_, state)| *state,
+        |(world, _)| world.top(),
+    );
+    let part2 = cycle.extrapolate(PART2_NUM_ITERATIONS, &heights);
 
     (part1.to_string(), part2.to_string())
 }
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 18,
     name: "Day 18: Boiling Boulders",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,8 +1,12 @@
 use std::sync::{Arc, atomic::AtomicU32};
 
+use common_v2::progress;
+
 mod parse;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 19,
     name: "Day 19: Not Enough Minerals",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -180,14 +184,24 @@ pub fn solve(input: &[u8]) -> (String, String) {
 
     // Part 1.
     let geodes = Arc::new(AtomicU32::new(0));
+    let finished = Arc::new(AtomicU32::new(0));
     let mut handles = Vec::new();
 
     for (i, blueprint) in blueprints.iter().enumerate() {
         let blueprint = blueprint.clone();
         let geodes = geodes.clone();
+        let finished = finished.clone();
+        let total = blueprints.len();
         let handle = std::thread::spawn(move || {
             let quality = (i as u32 + 1) * max_geodes(24, State::default(), &blueprint);
             geodes.fetch_add(quality, std::sync::atomic::Ordering::SeqCst);
+            let done = finished.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            let message = format_args!("part 1, {done} of {total} blueprints");
+            if done as usize == total {
+                progress::status_now(message);
+            } else {
+                progress::status(message);
+            }
         });
         handles.push(handle);
     }
@@ -199,13 +213,23 @@ pub fn solve(input: &[u8]) -> (String, String) {
 
     // Part 2.
     let geodes = Arc::new(AtomicU32::new(1));
+    let finished = Arc::new(AtomicU32::new(0));
     let mut handles = Vec::new();
 
     for blueprint in blueprints.iter().take(3) {
         let blueprint = blueprint.clone();
         let geodes = geodes.clone();
+        let finished = finished.clone();
+        let total = blueprints.len().min(3);
         let handle = std::thread::spawn(move || {
             let max = max_geodes(32, State::default(), &blueprint);
+            let done = finished.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            let message = format_args!("part 2, {done} of {total} blueprints");
+            if done as usize == total {
+                progress::status_now(message);
+            } else {
+                progress::status(message);
+            }
             geodes
                 .fetch_update(
                     std::sync::atomic::Ordering::SeqCst,
@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 2,
     name: "Day 2: Rock Paper Scissors",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 20,
     name: "Day 20: Grove Positioning System",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -6,6 +6,8 @@ use data::Monkey;
 type Number = num::Rational64;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 21,
     name: "Day 21: Monkey Math",
     input: std::include_bytes!("input"),
     solve: self::solve,
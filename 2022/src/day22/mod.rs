@@ -3,6 +3,8 @@ use std::collections::HashMap;
 mod parse;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 22,
     name: "Day 22: Monkey Map",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 23,
     name: "Day 23: Unstable Diffusion",
     input: std::include_bytes!("input"),
     solve: self::solve,
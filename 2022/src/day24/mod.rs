@@ -3,6 +3,8 @@ mod world;
 use world::World;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 24,
     name: "Day 24: Blizzard Basin",
     input: std::include_bytes!("input"),
     solve: self::solve,
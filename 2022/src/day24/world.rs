@@ -1,8 +1,11 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt::Display,
     mem,
 };
 
+use common_v2::visual;
+
 #[derive(Debug, Clone, Copy)]
 enum WindDirection {
     Up,
@@ -121,6 +124,7 @@ impl World {
         current_step.insert(start);
 
         'outer: loop {
+            visual::frame(self.display_at(*step, &current_step));
             for &(x, y) in current_step.iter() {
                 if (x, y) == end {
                     break 'outer;
@@ -172,41 +176,71 @@ impl World {
         self.end
     }
 
-    #[allow(dead_code)]
-    pub fn print_for_steps(&self, step_from: usize, step_until: usize) {
-        for step in step_from..step_until {
-            println!();
-            println!("Step {step}:");
-            for y in self.top..=self.bottom {
-                for x in self.left..=self.right {
-                    if let Some(&(_, dir)) = self
-                        .wind_vertical
-                        .get(&x)
-                        .and_then(|v| v.iter().find(|(y_, dir)| dir.move_n(*y_, step, self) == y))
-                        .or_else(|| {
-                            self.wind_horizontal.get(&y).and_then(|v| {
-                                v.iter().find(|(x_, dir)| dir.move_n(*x_, step, self) == x)
-                            })
+    /// Displays the winds after `step` steps, with the positions the
+    /// expedition may be in.
+    fn display_at<'a>(
+        &'a self,
+        step: usize,
+        expedition: &'a HashSet<(usize, usize)>,
+    ) -> WorldDisplay<'a> {
+        WorldDisplay {
+            world: self,
+            step,
+            expedition,
+        }
+    }
+}
+
+pub struct WorldDisplay<'a> {
+    world: &'a World,
+    step: usize,
+    expedition: &'a HashSet<(usize, usize)>,
+}
+
+impl Display for WorldDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let World {
+            wind_vertical,
+            wind_horizontal,
+            walls,
+            ..
+        } = self.world;
+        let step = self.step;
+
+        writeln!(f, "Minute {step}:")?;
+        for y in self.world.top..=self.world.bottom {
+            for x in self.world.left..=self.world.right {
+                let wind = wind_vertical
+                    .get(&x)
+                    .and_then(|v| {
+                        v.iter()
+                            .find(|(y_, dir)| dir.move_n(*y_, step, self.world) == y)
+                    })
+                    .or_else(|| {
+                        wind_horizontal.get(&y).and_then(|v| {
+                            v.iter()
+                                .find(|(x_, dir)| dir.move_n(*x_, step, self.world) == x)
                         })
-                    {
-                        match dir {
-                            WindDirection::Up => print!("^"),
-                            WindDirection::Down => print!("v"),
-                            WindDirection::Left => print!("<"),
-                            WindDirection::Right => print!(">"),
-                        }
-                    } else if self.walls.contains(&(x, y)) {
-                        print!("#");
-                    } else if self.start == (x, y) {
-                        print!("S");
-                    } else if self.end == (x, y) {
-                        print!("E");
-                    } else {
-                        print!(".");
+                    });
+
+                let c = if self.expedition.contains(&(x, y)) {
+                    'E'
+                } else if let Some(&(_, dir)) = wind {
+                    match dir {
+                        WindDirection::Up => '^',
+                        WindDirection::Down => 'v',
+                        WindDirection::Left => '<',
+                        WindDirection::Right => '>',
                     }
-                }
-                println!();
+                } else if walls.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
             }
+            writeln!(f)?;
         }
+        Ok(())
     }
 }
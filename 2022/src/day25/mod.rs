@@ -3,6 +3,8 @@ use self::snafu::Snafu;
 mod snafu;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 25,
     name: "Day 25: Full of Hot Air",
     input: std::include_bytes!("input"),
     solve: self::solve,
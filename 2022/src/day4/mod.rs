@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 4,
     name: "Day 4: Camp Cleanup",
     input: std::include_bytes!("input"),
     solve: self::solve,
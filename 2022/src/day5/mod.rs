@@ -1,6 +1,8 @@
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 5,
     name: "Day 5: Supply Stacks",
     input: std::include_bytes!("input"),
     solve: self::solve,
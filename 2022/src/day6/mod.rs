@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 6,
     name: "Day 6: Tuning Trouble",
     input: std::include_bytes!("input"),
     solve: self::solve,
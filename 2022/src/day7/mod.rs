@@ -4,6 +4,8 @@ use std::{
 };
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 7,
     name: "Day 7: No Space Left On Device",
     input: std::include_bytes!("input"),
     solve: self::solve,
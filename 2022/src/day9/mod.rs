@@ -3,6 +3,8 @@ use std::collections::HashSet;
 use regex::Regex;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2022,
+    day: 9,
     name: "Day 9: Rope Bridge",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -3,6 +3,8 @@ use std::fmt::Debug;
 use self::parse::{parse_alphanumeric_digit0, parse_digit0};
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 1,
     name: "Day 1: Trebuchet?!",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -4,6 +4,8 @@ use std::{
 };
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 10,
     name: "Day 10: Pipe Maze",
     input: std::include_bytes!("input"),
     solve: self::solve,
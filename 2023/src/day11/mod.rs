@@ -1,11 +1,15 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 11,
     name: "Day 11: Cosmic Expansion",
     input: std::include_bytes!("input"),
     solve: self::solve,
 };
 
 use std::collections::HashSet;
+use std::fmt::Display;
 
+use common_v2::visual;
 use itertools::Itertools;
 use std::ops::RangeInclusive;
 
@@ -53,7 +57,7 @@ pub fn solve(input: &[u8]) -> (String, String) {
 
     // Part 1
     let expanded_universe = galaxies.iter().map(expand_by(2)).collect::<HashSet<_>>();
-    // _print_universe(&expanded_universe);
+    visual::frame(Universe(&expanded_universe));
 
     let part1: usize = expanded_universe
         .iter()
@@ -74,22 +78,32 @@ pub fn solve(input: &[u8]) -> (String, String) {
     (part1.to_string(), part2.to_string())
 }
 
-fn _print_universe(universe: &HashSet<(usize, usize)>) {
-    let (x_bound, y_bound) = _get_bounds(universe.iter());
+/// The galaxies of the universe.
+struct Universe<'a>(&'a HashSet<(usize, usize)>);
 
-    for y in y_bound {
-        for x in x_bound.clone() {
-            let c = match universe.get(&(x, y)) {
-                Some(_) => '#',
-                None => '.',
-            };
-            print!("{c}");
+impl Display for Universe<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Universe(universe) = self;
+        if universe.is_empty() {
+            return Ok(());
         }
-        println!()
+        let (x_bound, y_bound) = get_bounds(universe.iter());
+
+        for y in y_bound {
+            for x in x_bound.clone() {
+                let c = match universe.get(&(x, y)) {
+                    Some(_) => '#',
+                    None => '.',
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
-fn _get_bounds<'a, T, I>(coords: I) -> (RangeInclusive<T>, RangeInclusive<T>)
+fn get_bounds<'a, T, I>(coords: I) -> (RangeInclusive<T>, RangeInclusive<T>)
 where
     I: Iterator<Item = &'a (T, T)> + Clone,
     T: Ord + Clone + 'a,
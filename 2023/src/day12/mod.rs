@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 12,
     name: "Day 12: Hot Springs",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,11 +1,15 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt::Display,
     ops::RangeInclusive,
 };
 
+use common_v2::visual;
 use itertools::Itertools;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 13,
     name: "Day 13: Point of Incidence",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -27,8 +31,10 @@ pub fn solve(input: &[u8]) -> (String, String) {
 fn summarise(smudges: usize) -> impl Fn(&HashSet<(u32, u32)>) -> u32 {
     move |ground: &HashSet<(u32, u32)>| -> u32 {
         let (mirror_x, mirror_y) = find_mirror(ground, smudges);
-        // _print_ground(&ground, (mirror_x.map(|x| x + 1), mirror_y.map(|y| y + 1)));
-        // println!();
+        visual::frame(Ground {
+            ground,
+            mirror: (mirror_x.map(|x| x + 1), mirror_y.map(|y| y + 1)),
+        });
         mirror_x.unwrap_or(0) + mirror_y.map(|y| y * 100).unwrap_or(0)
     }
 }
@@ -90,26 +96,41 @@ fn find_mirror(ground: &HashSet<(u32, u32)>, smudges: usize) -> (Option<u32>, Op
     (mirror_x, mirror_y)
 }
 
-fn _print_ground(universe: &HashSet<(u32, u32)>, mirror: (Option<u32>, Option<u32>)) {
-    let (x_bound, y_bound) = get_bounds(universe.iter());
-    let (mirror_x, mirror_y) = mirror;
+/// A pattern of ash and rocks, with the line of the mirror drawn in.
+struct Ground<'a> {
+    ground: &'a HashSet<(u32, u32)>,
+    /// Column and row the mirror is in front of.
+    mirror: (Option<u32>, Option<u32>),
+}
 
-    for y in y_bound {
-        if mirror_y.is_some_and(|my| my == y) {
-            x_bound.clone().for_each(|_| print!("\x1b[33m─\x1b[0m"));
-            println!();
+impl Display for Ground<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.ground.is_empty() {
+            return Ok(());
         }
-        for x in x_bound.clone() {
-            if mirror_x.is_some_and(|mx| mx == x) {
-                print!("\x1b[33m│\x1b[0m");
+        let (x_bound, y_bound) = get_bounds(self.ground.iter());
+        let (mirror_x, mirror_y) = self.mirror;
+
+        for y in y_bound {
+            if mirror_y.is_some_and(|my| my == y) {
+                for _ in x_bound.clone() {
+                    write!(f, "\x1b[33m─\x1b[0m")?;
+                }
+                writeln!(f)?;
+            }
+            for x in x_bound.clone() {
+                if mirror_x.is_some_and(|mx| mx == x) {
+                    write!(f, "\x1b[33m│\x1b[0m")?;
+                }
+                let c = match self.ground.get(&(x, y)) {
+                    Some(_) => '#',
+                    None => '.',
+                };
+                write!(f, "{c}")?;
             }
-            let c = match universe.get(&(x, y)) {
-                Some(_) => '#',
-                None => '.',
-            };
-            print!("{c}");
+            writeln!(f)?;
         }
-        println!()
+        Ok(())
     }
 }
 
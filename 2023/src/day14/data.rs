@@ -1,5 +1,6 @@
 use std::{
     collections::{HashMap, hash_map::DefaultHasher},
+    fmt::Display,
     hash::{Hash, Hasher},
     ops::Range,
 };
@@ -132,3 +133,20 @@ impl Platform {
             .sum()
     }
 }
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.y_bound.clone() {
+            for x in self.x_bound.clone() {
+                let c = match self.rocks.get(&(x, y)) {
+                    Some(Rock::Cubed) => '#',
+                    Some(Rock::Round) => 'O',
+                    None => '.',
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
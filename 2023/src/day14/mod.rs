@@ -1,10 +1,13 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 14,
     name: "Day 14: Parabolic Reflector Dish",
     input: std::include_bytes!("input"),
     solve: self::solve,
 };
 
-use std::{collections::HashMap, ops::Range};
+use common::cycle;
+use common_v2::visual;
 
 mod data;
 mod parse;
@@ -19,59 +22,28 @@ pub fn solve(input: &[u8]) -> (String, String) {
     // Part 1
     let mut platform = platform_initial.clone();
     platform.fall_north();
+    visual::frame(&platform);
     let part1 = platform.load_north();
 
     // Part 2
-    let mut platform = platform_initial;
-    let mut history: HashMap<u64, usize> = HashMap::new();
-    let mut repeat_range: Range<usize> = 0..0;
-
-    for cycle in 1..=1_000_000_000 {
-        platform.fall_north();
-        platform.fall_west();
-        platform.fall_south();
-        let hash = platform.fall_east();
-
-        if let Some(prev_cycle) = history.get(&hash) {
-            // Optimisation: when the cycles starts to repeat, we quit early.
-            repeat_range = *prev_cycle..cycle;
-            break;
-        }
-
-        history.insert(hash, cycle);
-    }
-
-    // Skip all the repeating cycles and only do the remaining ones.
-    let remaining_cycles = (1_000_000_000 - repeat_range.end) % repeat_range.len();
-    for _ in 0..remaining_cycles {
-        platform.fall_north();
-        platform.fall_west();
-        platform.fall_south();
-        platform.fall_east();
-    }
-    let part2 = platform.load_north();
-
-    // println!("\n1000000000th cycle");
-    // _print_platform(&platform);
-    // println!();
+    // Each fall returns a hash of the platform, the last one is used as key.
+    let (cycle, loads) = cycle::find(
+        &mut (platform_initial, None),
+        |(platform, hash)| {
+            platform.fall_north();
+            platform.fall_west();
+            platform.fall_south();
+            *hash = Some(platform.fall_east());
+            visual::frame(&*platform);
+        },
+        |(_, hash)| *hash,
+        |(platform, _)| platform.load_north(),
+    );
+    let part2 = loads[cycle.equivalent(1_000_000_000)];
 
     (part1.to_string(), part2.to_string())
 }
 
-fn _print_platform(platform: &Platform) {
-    for y in platform.x_bound.clone() {
-        for x in platform.y_bound.clone() {
-            let c = match platform.rocks.get(&(x, y)) {
-                Some(Rock::Cubed) => '#',
-                Some(Rock::Round) => 'O',
-                None => '.',
-            };
-            print!("{c}");
-        }
-        println!()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 15,
     name: "Day 15: Lens Library",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 16,
     name: "Day 16: The Floor Will Be Lava",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -7,11 +9,13 @@ pub const SOLUTION: common::Solution = common::Solution {
 mod data;
 mod parse;
 
-use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
 
+use common_v2::visual;
 use rayon::prelude::*;
 
-use self::data::{Contraption, Direction, Mirror};
+use self::data::{Contraption, Coord, Direction, Mirror};
 
 pub fn solve(input: &[u8]) -> (String, String) {
     let input = String::from_utf8_lossy(input);
@@ -20,10 +24,10 @@ pub fn solve(input: &[u8]) -> (String, String) {
     let contraption = parse::parse_contraption(&input);
     let beam = contraption.fire_beam(((1, 1), Direction::East));
     let part1 = beam.len();
-
-    // println!();
-    // _print_contraption(&contraption);
-    // println!();
+    visual::frame(Energised {
+        contraption: &contraption,
+        beam: &beam,
+    });
 
     // Part 2
     let top_edge = contraption.x_bound.clone().into_par_iter().map(|x| {
@@ -57,44 +61,29 @@ pub fn solve(input: &[u8]) -> (String, String) {
     (part1.to_string(), part2.to_string())
 }
 
-fn _print_contraption(ctt: &Contraption) {
-    for y in ctt.y_bound.clone() {
-        for x in ctt.x_bound.clone() {
-            let c = match ctt.mirrors.get(&(x, y)) {
-                Some(Mirror::Left) => '\\',
-                Some(Mirror::Right) => '/',
-                Some(Mirror::SplitV) => '|',
-                Some(Mirror::SplitH) => '-',
-                None => '.',
-            };
-            print!("{c}");
-        }
-        println!()
-    }
+/// The contraption with the tiles energised by a beam.
+struct Energised<'a> {
+    contraption: &'a Contraption,
+    beam: &'a HashSet<Coord>,
 }
 
-fn _print_contraption_coords(ctt: &Contraption, coords: &HashMap<data::Coord, data::Direction>) {
-    for y in ctt.y_bound.clone() {
-        for x in ctt.x_bound.clone() {
-            if let Some(dir) = coords.get(&(x, y)) {
-                match dir {
-                    data::Direction::North => print!("^"),
-                    data::Direction::West => print!("<"),
-                    data::Direction::South => print!("V"),
-                    data::Direction::East => print!(">"),
-                }
-                continue;
+impl Display for Energised<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.contraption.y_bound.clone() {
+            for x in self.contraption.x_bound.clone() {
+                let c = match self.contraption.mirrors.get(&(x, y)) {
+                    Some(Mirror::Left) => '\\',
+                    Some(Mirror::Right) => '/',
+                    Some(Mirror::SplitV) => '|',
+                    Some(Mirror::SplitH) => '-',
+                    None if self.beam.contains(&(x, y)) => '#',
+                    None => '.',
+                };
+                write!(f, "{c}")?;
             }
-            let c = match ctt.mirrors.get(&(x, y)) {
-                Some(Mirror::Left) => '\\',
-                Some(Mirror::Right) => '/',
-                Some(Mirror::SplitV) => '|',
-                Some(Mirror::SplitH) => '-',
-                None => '.',
-            };
-            print!("{c}");
+            writeln!(f)?;
         }
-        println!()
+        Ok(())
     }
 }
 
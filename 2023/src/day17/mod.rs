@@ -1,6 +1,11 @@
 use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::Display;
+
+use common_v2::visual;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 17,
     name: "Day 17: Clumsy Crucible",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -179,10 +184,11 @@ fn solve_part2(heat_map: &HashMap<(usize, usize), u32>) -> u32 {
         }
     }
 
-    // println!();
-    // // _print_heat_loss(&heat_map, &heat_losses);
-    // _print_path(&heat_map, &heat_losses, target);
-    // println!();
+    visual::frame(Path {
+        heat_map,
+        heat_losses: &heat_losses,
+        target,
+    });
 
     *heat_losses
         .get(&target)
@@ -297,10 +303,11 @@ fn solve_part1(heat_map: &HashMap<(usize, usize), u32>) -> u32 {
         }
     }
 
-    // println!();
-    // // _print_heat_loss(&heat_map, &heat_losses);
-    // _print_path(&heat_map, &heat_losses, target);
-    // println!();
+    visual::frame(Path {
+        heat_map,
+        heat_losses: &heat_losses,
+        target,
+    });
 
     *heat_losses
         .get(&target)
@@ -308,73 +315,54 @@ fn solve_part1(heat_map: &HashMap<(usize, usize), u32>) -> u32 {
         .expect("should have visited bottom left corner")
 }
 
-fn _print_path(
-    heat_map: &HashMap<(usize, usize), u32>,
-    heat_losses: &HashMap<NodeID, (u32, NodeID)>,
+/// The city blocks with the path taken to `target` drawn as arrows.
+struct Path<'a> {
+    heat_map: &'a HashMap<(usize, usize), u32>,
+    heat_losses: &'a HashMap<NodeID, (u32, NodeID)>,
     target: NodeID,
-) {
-    let x_min = heat_map.keys().map(|(x, _)| *x).min().unwrap_or_default();
-    let y_min = heat_map.keys().map(|(_, y)| *y).min().unwrap_or_default();
-    let x_max = heat_map.keys().map(|(x, _)| *x).max().unwrap_or_default();
-    let y_max = heat_map.keys().map(|(_, y)| *y).max().unwrap_or_default();
+}
 
-    let mut path: HashMap<Coord, Coord> = HashMap::new();
+impl Display for Path<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let heat_map = self.heat_map;
+        let x_min = heat_map.keys().map(|(x, _)| *x).min().unwrap_or_default();
+        let y_min = heat_map.keys().map(|(_, y)| *y).min().unwrap_or_default();
+        let x_max = heat_map.keys().map(|(x, _)| *x).max().unwrap_or_default();
+        let y_max = heat_map.keys().map(|(_, y)| *y).max().unwrap_or_default();
 
-    let mut current = target;
-    while let Some((_, prev)) = heat_losses.get(&current) {
-        path.insert(current.0, prev.0);
-        current = *prev;
-    }
+        let mut path: HashMap<Coord, Coord> = HashMap::new();
 
-    for y in y_min..=y_max {
-        for x in x_min..=x_max {
-            if let Some((x0, y0)) = path.get(&(x, y)) {
-                use std::cmp::Ordering as Ord;
-                match (x.cmp(x0), y.cmp(y0)) {
-                    (Ord::Less, Ord::Equal) => print!("\x1b[33m◀\x1b[0m"),
-                    (Ord::Equal, Ord::Less) => print!("\x1b[33m▲\x1b[0m"),
-                    (Ord::Equal, Ord::Greater) => print!("\x1b[33m▼\x1b[0m"),
-                    (Ord::Greater, Ord::Equal) => print!("\x1b[33m▶\x1b[0m"),
-                    (Ord::Less, Ord::Less) => print!("\x1b[33mA\x1b[0m"),
-                    (Ord::Less, Ord::Greater) => print!("\x1b[33mB\x1b[0m"),
-                    (Ord::Equal, Ord::Equal) => print!("\x1b[33mC\x1b[0m"),
-                    (Ord::Greater, Ord::Less) => print!("\x1b[33mD\x1b[0m"),
-                    (Ord::Greater, Ord::Greater) => print!("\x1b[33mE\x1b[0m"),
-                };
-            } else {
-                let c = heat_map.get(&(x, y)).unwrap();
-                print!("\x1b[90m{c}\x1b[0m");
-            }
+        let mut current = self.target;
+        while let Some((_, prev)) = self.heat_losses.get(&current) {
+            path.insert(current.0, prev.0);
+            current = *prev;
         }
-        println!()
-    }
-}
-
-fn _print_heat_loss(
-    heat_map: &HashMap<(usize, usize), u32>,
-    heat_loss: &HashMap<NodeID, (u32, NodeID)>,
-) {
-    let x_min = heat_map.keys().map(|(x, _)| *x).min().unwrap_or_default();
-    let y_min = heat_map.keys().map(|(_, y)| *y).min().unwrap_or_default();
-    let x_max = heat_map.keys().map(|(x, _)| *x).max().unwrap_or_default();
-    let y_max = heat_map.keys().map(|(_, y)| *y).max().unwrap_or_default();
 
-    for y in y_min..=y_max {
-        for x in x_min..=x_max {
-            if let Some(s) = heat_loss
-                .iter()
-                .filter_map(|((coord, _, _), (heat_loss, _))| {
-                    (*coord == (x, y)).then_some(heat_loss)
-                })
-                .min()
-            {
-                print!("\x1b[32m{s:>5}\x1b[0m");
-            } else {
-                let c = heat_map.get(&(x, y)).unwrap();
-                print!("\x1b[90m{c:>5}\x1b[0m");
+        for y in y_min..=y_max {
+            for x in x_min..=x_max {
+                if let Some((x0, y0)) = path.get(&(x, y)) {
+                    use std::cmp::Ordering as Ord;
+                    let c = match (x.cmp(x0), y.cmp(y0)) {
+                        (Ord::Less, Ord::Equal) => '◀',
+                        (Ord::Equal, Ord::Less) => '▲',
+                        (Ord::Equal, Ord::Greater) => '▼',
+                        (Ord::Greater, Ord::Equal) => '▶',
+                        (Ord::Less, Ord::Less) => 'A',
+                        (Ord::Less, Ord::Greater) => 'B',
+                        (Ord::Equal, Ord::Equal) => 'C',
+                        (Ord::Greater, Ord::Less) => 'D',
+                        (Ord::Greater, Ord::Greater) => 'E',
+                    };
+                    write!(f, "\x1b[33m{c}\x1b[0m")?;
+                } else if let Some(c) = heat_map.get(&(x, y)) {
+                    write!(f, "\x1b[90m{c}\x1b[0m")?;
+                } else {
+                    write!(f, " ")?;
+                }
             }
+            writeln!(f)?;
         }
-        println!()
+        Ok(())
     }
 }
 
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 18,
     name: "Day 18: Lavaduct Lagoon",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -7,18 +9,18 @@ pub const SOLUTION: common::Solution = common::Solution {
 mod data;
 mod parse;
 
+use std::collections::HashMap;
+use std::fmt::Display;
+
 use self::data::{Colour, Coord, DigStep};
+use common_v2::visual;
 use crossterm::style::Stylize;
-use std::collections::HashMap;
 
 pub fn solve(input: &[u8]) -> (String, String) {
     let input = String::from_utf8_lossy(input);
 
     let (_, dig_plan) = parse::parse_dig_plan(&input).expect("input should be valid");
-
-    // println!();
-    // _print_dig_plan(&dig_plan);
-    // println!();
+    visual::frame(DigPlan(&dig_plan));
 
     // Part 1
     let part1 = calc_area(dig_plan.iter().cloned());
@@ -56,31 +58,37 @@ fn calc_area(dig_steps: impl Iterator<Item = DigStep>) -> usize {
     (area.unsigned_abs() as usize + dist_total) / 2 + 1
 }
 
-fn _print_dig_plan(dig_plan: &[DigStep]) {
-    let mut terrain: HashMap<Coord, Colour> = HashMap::new();
-    let (mut x, mut y) = (0, 0);
-    for step in dig_plan.iter() {
-        let (x_offset, y_offset) = step.dir.offset();
-        for _ in 0..step.dist {
-            terrain.insert((x, y), step.colour);
-            (x, y) = (x + x_offset, y + y_offset);
+/// The trench dug by following the plan, in the colours of the plan.
+struct DigPlan<'a>(&'a [DigStep]);
+
+impl Display for DigPlan<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut terrain: HashMap<Coord, Colour> = HashMap::new();
+        let (mut x, mut y) = (0, 0);
+        for step in self.0.iter() {
+            let (x_offset, y_offset) = step.dir.offset();
+            for _ in 0..step.dist {
+                terrain.insert((x, y), step.colour);
+                (x, y) = (x + x_offset, y + y_offset);
+            }
         }
-    }
 
-    let x_min = terrain.keys().map(|(x, _)| *x).min().unwrap_or_default();
-    let y_min = terrain.keys().map(|(_, y)| *y).min().unwrap_or_default();
-    let x_max = terrain.keys().map(|(x, _)| *x).max().unwrap_or_default();
-    let y_max = terrain.keys().map(|(_, y)| *y).max().unwrap_or_default();
-
-    for y in (y_min..=y_max).rev() {
-        for x in x_min..=x_max {
-            if let Some(&colour) = terrain.get(&(x, y)) {
-                print!("{}", "#".with(colour.into()));
-            } else {
-                print!("{}", ".".dark_grey());
+        let x_min = terrain.keys().map(|(x, _)| *x).min().unwrap_or_default();
+        let y_min = terrain.keys().map(|(_, y)| *y).min().unwrap_or_default();
+        let x_max = terrain.keys().map(|(x, _)| *x).max().unwrap_or_default();
+        let y_max = terrain.keys().map(|(_, y)| *y).max().unwrap_or_default();
+
+        for y in (y_min..=y_max).rev() {
+            for x in x_min..=x_max {
+                if let Some(&colour) = terrain.get(&(x, y)) {
+                    write!(f, "{}", "#".with(colour.into()))?;
+                } else {
+                    write!(f, "{}", ".".dark_grey())?;
+                }
             }
+            writeln!(f)?;
         }
-        println!()
+        Ok(())
     }
 }
 
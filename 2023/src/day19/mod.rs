@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 19,
     name: "Day 19: Aplenty",
     input: std::include_bytes!("input"),
     solve: self::solve,
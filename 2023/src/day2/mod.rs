@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 2,
     name: "Day 2: Cube Conundrum",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -48,7 +50,7 @@ impl Game {
 pub fn solve(input: &[u8]) -> (String, String) {
     let input = String::from_utf8_lossy(input);
 
-    let (_, games) = parse::parse_game0(input.as_ref()).unwrap();
+    let (_, games) = parse::parse_game0(input.as_ref()).expect("input should be valid");
     (
         games
             .iter()
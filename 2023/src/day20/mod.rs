@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 20,
     name: "Day 20: Pulse Propagation",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 21,
     name: "Day 21: Step Counter",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -10,7 +12,9 @@ mod parse;
 pub type Coord = (i32, i32);
 
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
 
+use common_v2::visual;
 use crossterm::style::Stylize;
 
 pub fn solve(input: &[u8]) -> (String, String) {
@@ -35,12 +39,16 @@ pub fn solve_for_steps(input: &[u8], steps: usize) -> (String, String) {
         let new_dist = dist + 1;
 
         if new_dist == steps as isize + 2 {
-            // println!("\n FINAL SOLUTION FOR STEP {steps}:");
-            // _print_garden_map(&rocks, &visited);
+            visual::frame(GardenMap {
+                rocks: &rocks,
+                visited: &visited,
+            });
             break visited.len();
         } else if new_dist != current_dist {
-            // println!("\n AFTER {} STEPS:", current_dist - 2);
-            // _print_garden_map(&rocks, &visited);
+            visual::frame(GardenMap {
+                rocks: &rocks,
+                visited: &visited,
+            });
             visited.clear();
             current_dist = new_dist;
         }
@@ -63,43 +71,56 @@ pub fn solve_for_steps(input: &[u8], steps: usize) -> (String, String) {
     (part1.to_string(), 0.to_string())
 }
 
-fn _print_garden_map(rocks: &HashSet<Coord>, visited: &HashSet<Coord>) {
-    let x_min = rocks
-        .iter()
-        .chain(visited.iter())
-        .map(|(x, _)| *x)
-        .min()
-        .unwrap_or_default();
-    let y_min = rocks
-        .iter()
-        .chain(visited.iter())
-        .map(|(_, y)| *y)
-        .min()
-        .unwrap_or_default();
-    let x_max = rocks
-        .iter()
-        .chain(visited.iter())
-        .map(|(x, _)| *x)
-        .max()
-        .unwrap_or_default();
-    let y_max = rocks
-        .iter()
-        .chain(visited.iter())
-        .map(|(_, y)| *y)
-        .max()
-        .unwrap_or_default();
-
-    for y in y_min..=y_max {
-        for x in x_min..=x_max {
-            if visited.get(&(x, y)).is_some() {
-                print!("O");
-            } else if rocks.get(&(x, y)).is_some() {
-                print!("#");
-            } else {
-                print!("{}", ".".dark_grey());
+/// The garden with the plots reached in the last step.
+struct GardenMap<'a> {
+    rocks: &'a HashSet<Coord>,
+    visited: &'a HashSet<Coord>,
+}
+
+impl Display for GardenMap<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let x_min = self
+            .rocks
+            .iter()
+            .chain(self.visited.iter())
+            .map(|(x, _)| *x)
+            .min()
+            .unwrap_or_default();
+        let y_min = self
+            .rocks
+            .iter()
+            .chain(self.visited.iter())
+            .map(|(_, y)| *y)
+            .min()
+            .unwrap_or_default();
+        let x_max = self
+            .rocks
+            .iter()
+            .chain(self.visited.iter())
+            .map(|(x, _)| *x)
+            .max()
+            .unwrap_or_default();
+        let y_max = self
+            .rocks
+            .iter()
+            .chain(self.visited.iter())
+            .map(|(_, y)| *y)
+            .max()
+            .unwrap_or_default();
+
+        for y in y_min..=y_max {
+            for x in x_min..=x_max {
+                if self.visited.get(&(x, y)).is_some() {
+                    write!(f, "O")?;
+                } else if self.rocks.get(&(x, y)).is_some() {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, "{}", ".".dark_grey())?;
+                }
             }
+            writeln!(f)?;
         }
-        println!()
+        Ok(())
     }
 }
 
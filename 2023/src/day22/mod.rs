@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 22,
     name: "Day 22: Sand Slabs",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
+use common_v2::visual;
 use crossterm::style::Stylize;
 use itertools::Itertools;
 
@@ -8,6 +10,8 @@ use crate::day23::data::Tile;
 use self::data::{Coord, HikingTrails};
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 23,
     name: "Day 23: A Long Walk",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -28,8 +32,7 @@ pub fn solve(input: &[u8]) -> (String, String) {
     trails.map.insert((ex, ey + 1), Tile::Forest);
 
     let trails = trails;
-
-    // _print_hiking_trails(&trails);
+    visual::frame(Trails(&trails));
 
     // Part 1
     // Basic DFS, though we don't terminate early and instead wait until we
@@ -182,42 +185,49 @@ pub fn solve(input: &[u8]) -> (String, String) {
     (part1.to_string(), part2.to_string())
 }
 
-fn _print_hiking_trails(trails: &HikingTrails) {
-    let (&x_min, &x_max) = trails
-        .map
-        .keys()
-        .map(|(x, _)| x)
-        .minmax()
-        .into_option()
-        .unwrap_or((&0, &0));
-    let (&y_min, &y_max) = trails
-        .map
-        .keys()
-        .map(|(_, y)| y)
-        .minmax()
-        .into_option()
-        .unwrap_or((&0, &0));
-
-    for y in y_min..=y_max {
-        for x in x_min..=x_max {
-            if (x, y) == trails.start {
-                print!("S");
-                continue;
-            }
-            if (x, y) == trails.end {
-                print!("E");
-                continue;
-            }
-            match trails.map.get(&(x, y)) {
-                Some(Tile::Forest) => print!("#"),
-                Some(Tile::SlopeNorth) => print!("^"),
-                Some(Tile::SlopeEast) => print!(">"),
-                Some(Tile::SlopeSouth) => print!("v"),
-                Some(Tile::SlopeWest) => print!("<"),
-                None => print!("{}", ".".dark_grey()),
+/// The map of the hiking trails.
+struct Trails<'a>(&'a HikingTrails);
+
+impl Display for Trails<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let trails = self.0;
+        let (&x_min, &x_max) = trails
+            .map
+            .keys()
+            .map(|(x, _)| x)
+            .minmax()
+            .into_option()
+            .unwrap_or((&0, &0));
+        let (&y_min, &y_max) = trails
+            .map
+            .keys()
+            .map(|(_, y)| y)
+            .minmax()
+            .into_option()
+            .unwrap_or((&0, &0));
+
+        for y in y_min..=y_max {
+            for x in x_min..=x_max {
+                if (x, y) == trails.start {
+                    write!(f, "S")?;
+                    continue;
+                }
+                if (x, y) == trails.end {
+                    write!(f, "E")?;
+                    continue;
+                }
+                match trails.map.get(&(x, y)) {
+                    Some(Tile::Forest) => write!(f, "#")?,
+                    Some(Tile::SlopeNorth) => write!(f, "^")?,
+                    Some(Tile::SlopeEast) => write!(f, ">")?,
+                    Some(Tile::SlopeSouth) => write!(f, "v")?,
+                    Some(Tile::SlopeWest) => write!(f, "<")?,
+                    None => write!(f, "{}", ".".dark_grey())?,
+                }
             }
+            writeln!(f)?;
         }
-        println!()
+        Ok(())
     }
 }
 
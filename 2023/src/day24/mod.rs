@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 24,
     name: "Day 24: Never Tell Me The Odds",
     input: std::include_bytes!("input"),
     solve: self::solve,
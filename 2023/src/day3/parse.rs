@@ -27,8 +27,7 @@ pub(super) fn parse_engine_symbol(input: &str) -> IResult<&str, (Part, usize)> {
 fn _parse_engine(mut input: &str) -> IResult<&str, Engine> {
     let mut engine = Engine::new();
 
-    let mut y: usize = 1;
-    for line in input.lines() {
+    for (y, line) in (1_usize..).zip(input.lines()) {
         let mut x: usize = 1;
         let mut n;
         let mut part;
@@ -50,8 +49,6 @@ fn _parse_engine(mut input: &str) -> IResult<&str, Engine> {
             (input, n) = take_void(input)?;
             x += n;
         }
-
-        y += 1;
     }
 
     Ok((input, engine))
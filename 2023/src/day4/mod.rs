@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 4,
     name: "Day 4: Scratchcards",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -2,6 +2,8 @@ use itertools::Itertools;
 use std::{collections::HashMap, ops::Range};
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 5,
     name: "Day 5: If You Give A Seed A Fertilizer",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 6,
     name: "Day 6: Wait For It",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 7,
     name: "Day 7: Camel Cards",
     input: std::include_bytes!("input"),
     solve: self::solve,
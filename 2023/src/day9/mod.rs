@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2023,
+    day: 9,
     name: "Day 9: Mirage Maintenance",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 1,
     name: "Day 1: Historian Hysteria",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -19,10 +21,10 @@ pub fn solve(input: &[u8]) -> (String, String) {
     right_list.sort_unstable();
 
     // Part 1
-    let part1: u32 = left_list
+    let part1: u64 = left_list
         .iter()
         .zip(right_list.iter())
-        .map(|(lhs, rhs)| lhs.abs_diff(*rhs))
+        .map(|(lhs, rhs)| u64::from(lhs.abs_diff(*rhs)))
         .sum();
 
     // Part 2
@@ -33,9 +35,11 @@ pub fn solve(input: &[u8]) -> (String, String) {
             acc
         });
 
-    let part2: u32 = left_list
+    // Summed as `u64`, as a large number appearing several times in both
+    // lists would not fit in a `u32`.
+    let part2: u64 = left_list
         .iter()
-        .map(|value| value * freq_map.get(value).unwrap_or(&0))
+        .map(|value| u64::from(*value) * u64::from(*freq_map.get(value).unwrap_or(&0)))
         .sum();
 
     (part1.to_string(), part2.to_string())
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 11,
     name: "Day 11: Plutonian Pebbles",
     input: std::include_bytes!("input"),
     solve: self::solve,
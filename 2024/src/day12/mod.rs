@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 12,
     name: "Day 12: Garden Groups",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 13,
     name: "Day 13: Claw Contraption",
     input: std::include_bytes!("input"),
     solve: self::solve,
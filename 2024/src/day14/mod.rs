@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 14,
     name: "Day 14: Restroom Redoubt",
     input: std::include_bytes!("input"),
     solve: self::solve,
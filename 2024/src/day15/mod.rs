@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 15,
     name: "Day 15: Warehouse Woes",
     input: std::include_bytes!("input"),
     solve: self::solve,
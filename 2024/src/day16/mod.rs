@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 16,
     name: "Day 16: Reindeer Maze",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -6,9 +8,9 @@ pub const SOLUTION: common::Solution = common::Solution {
 
 mod data;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
-use itertools::Itertools;
+use common::search;
 use nalgebra::Vector2;
 
 use data::{Direction, TileNode};
@@ -44,118 +46,45 @@ pub fn solve(input: &[u8]) -> (String, String) {
         }
     }
 
-    // Uses Dijkstra's algorithm to find the shortest paths. It's a mess, but
-    // it works and I am too tired to keep working on this problem.
     let start_pos = start.unwrap_or_else(|| panic!("input missing start location"));
     let end_pos = end.unwrap_or_else(|| panic!("input missing end location"));
 
-    let mut next: Vec<TileNode> = Vec::new();
-    let mut cost: HashMap<TileNode, u32> = HashMap::new();
-    let mut visited: HashSet<TileNode> = HashSet::new();
-    let mut previous: HashMap<TileNode, Vec<TileNode>> = HashMap::new();
-
-    let start = TileNode::new(start_pos, Direction::East);
-    cost.insert(start, 0);
-    next.push(start);
-
-    while !next.is_empty() {
-        let current = next.remove(next.iter().position_min_by_key(|v| cost.get(v)).unwrap());
-        let current_cost = *cost
-            .get(&current)
-            .expect("should have a cost set by this point");
-        visited.insert(TileNode::new(current.pos, current.dir));
-
-        for dir in Direction::all_directions() {
-            if dir == current.dir {
-                let neighbour_pos = current.pos + dir.to_vector();
-                let new_cost = current_cost + 1;
-
-                if walls.contains(&neighbour_pos)
-                    || visited.contains(&TileNode::new(neighbour_pos, dir))
-                {
-                    continue;
-                }
-
-                if !next.contains(&TileNode::new(neighbour_pos, dir)) {
-                    next.push(TileNode::new(neighbour_pos, dir));
-                }
-
-                let neighbour_cost = cost
-                    .entry(TileNode::new(neighbour_pos, dir))
-                    .or_insert(u32::MAX);
-
-                match new_cost.cmp(neighbour_cost) {
-                    std::cmp::Ordering::Less => {
-                        *neighbour_cost = new_cost;
-                        previous.insert(
-                            TileNode::new(neighbour_pos, dir),
-                            vec![TileNode::new(current.pos, current.dir)],
-                        );
-                    }
-                    std::cmp::Ordering::Equal => {
-                        previous
-                            .entry(TileNode::new(neighbour_pos, dir))
-                            .and_modify(|v| v.push(TileNode::new(current.pos, current.dir)))
-                            .or_insert_with(|| vec![TileNode::new(current.pos, current.dir)]);
-                    }
-                    std::cmp::Ordering::Greater => { /* Do nothing. */ }
-                }
-            } else {
-                if current.dir.rot_cost(dir) == 2000 {
-                    continue;
-                }
-
-                let new_cost = current_cost + current.dir.rot_cost(dir);
-
-                if visited.contains(&TileNode::new(current.pos, dir)) {
-                    continue;
-                }
-
-                if !next.contains(&TileNode::new(current.pos, dir)) {
-                    next.push(TileNode::new(current.pos, dir));
-                }
-
-                let neighbour_cost = cost
-                    .entry(TileNode::new(current.pos, dir))
-                    .or_insert(u32::MAX);
-
-                match new_cost.cmp(neighbour_cost) {
-                    std::cmp::Ordering::Less => {
-                        *neighbour_cost = new_cost;
-                        previous.insert(
-                            TileNode::new(current.pos, dir),
-                            vec![TileNode::new(current.pos, current.dir)],
-                        );
-                    }
-                    std::cmp::Ordering::Equal => {
-                        previous
-                            .entry(TileNode::new(current.pos, dir))
-                            .and_modify(|v| v.push(TileNode::new(current.pos, current.dir)))
-                            .or_insert_with(|| vec![TileNode::new(current.pos, current.dir)]);
-                    }
-                    std::cmp::Ordering::Greater => { /* Do nothing. */ }
-                }
-            }
-        }
-    }
+    // Moving forward or turning a quarter are the only steps. Turning around
+    // is never part of a shortest path.
+    let neighbours = |current: &TileNode| {
+        Direction::all_directions()
+            .filter(|&dir| current.dir.rot_cost(dir) == 1000)
+            .map(|dir| TileNode::new(current.pos, dir))
+            .chain(
+                Some(current.pos + current.dir.to_vector())
+                    .filter(|pos| !walls.contains(pos))
+                    .map(|pos| TileNode::new(pos, current.dir)),
+            )
+            .collect::<Vec<_>>()
+    };
+    let paths = search::dijkstra(
+        [TileNode::new(start_pos, Direction::East)],
+        neighbours,
+        |from, to| match from.dir.rot_cost(to.dir) {
+            0 => 1,
+            turn => turn,
+        },
+        |current| current.pos == end_pos,
+    );
 
     // Part 1
-    let part1 = cost
-        .get(&TileNode::new(end_pos, Direction::North))
+    let part1 = paths
+        .goal_cost()
         .expect("should be a path from start to end");
 
     // Part 2
-    let mut best_tiles: HashSet<Vector2<i32>> = HashSet::new();
-    let mut next: Vec<TileNode> = Vec::new();
-    next.push(TileNode::new(end_pos, Direction::North));
-
-    while let Some(current) = next.pop() {
-        best_tiles.insert(current.pos);
-
-        for &prev in previous.get(&current).into_iter().flatten() {
-            next.push(prev);
-        }
-    }
+    // The end may be reached facing different ways for the same cost.
+    let best_tiles: HashSet<Vector2<i32>> = Direction::all_directions()
+        .map(|dir| TileNode::new(end_pos, dir))
+        .filter(|end| paths.cost(end) == Some(part1))
+        .flat_map(|end| paths.on_shortest_paths(&end))
+        .map(|node| node.pos)
+        .collect();
 
     let part2 = best_tiles.len();
 
@@ -226,12 +155,7 @@ mod tests {
 #################",
         "11048"
     );
-    solution!(
-        p1,
-        p1_solution,
-        "99448",
-        ignore = "too slow in debug release"
-    );
+    solution!(p1, p1_solution, "99448");
 
     // Part 2
     example!(
@@ -276,5 +200,5 @@ mod tests {
 #################",
         "64"
     );
-    solution!(p2, p2_solution, "498", ignore = "too slow in debug release");
+    solution!(p2, p2_solution, "498");
 }
@@ -202,6 +202,7 @@ impl From<Opcode> for u8 {
 }
 
 #[cfg(test)]
+#[allow(clippy::unnecessary_mut_passed)]
 mod tests {
     use std::vec;
 
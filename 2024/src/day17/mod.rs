@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 17,
     name: "Day 17: Chronospatial Computer",
     input: std::include_bytes!("input"),
     solve: self::solve,
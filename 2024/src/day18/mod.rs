@@ -1,14 +1,14 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 18,
     name: "Day 18: RAM Run",
     input: std::include_bytes!("input"),
     solve: self::solve,
 };
 
-use std::{
-    cmp::Ordering,
-    collections::{HashSet, VecDeque},
-};
+use std::{cmp::Ordering, collections::HashSet};
 
+use common::search;
 use itertools::Itertools;
 use nalgebra::Vector2;
 
@@ -36,83 +36,15 @@ pub fn solve_(input: &[u8], size: i32, count_fallen: usize) -> (String, String)
     }
 
     // Part 1
-    let mut next: VecDeque<(u32, Vector2<i32>)> = VecDeque::new();
-    let mut visited: HashSet<Vector2<i32>> = HashSet::new();
-    next.push_back((0, start));
-    visited.insert(start);
-
-    const OFFSETS: &[Vector2<i32>] = &[
-        Vector2::new(1, 0),
-        Vector2::new(0, 1),
-        Vector2::new(-1, 0),
-        Vector2::new(0, -1),
-    ];
-
-    'outer: while let Some((cost, current)) = next.pop_front() {
-        for offset in OFFSETS {
-            let neighbour = current + offset;
-
-            if visited.contains(&neighbour)
-                || fall_locations[..count_fallen].contains(&neighbour)
-                || !(0..=size).contains(&neighbour.x)
-                || !(0..=size).contains(&neighbour.y)
-            {
-                continue;
-            }
-
-            visited.insert(neighbour);
-            next.push_back((cost + 1, neighbour));
-
-            if neighbour == end {
-                break 'outer;
-            }
-        }
-    }
-
-    let part1 = next.pop_back().map(|v| v.0);
+    let part1 = shortest_path(&fall_locations[..count_fallen], start, end, size);
 
     // Part 2
     // Does a binary search to find the first time path finding fails to find
-    // the end location. Uses a built in binary search function in the standard
-    // library. Unfortunately it only works on slices, but I don't feel like
-    // implementing binary search myself right now.
+    // the end location.
     let location_index = (0..fall_locations.len())
         .collect::<Vec<usize>>() // Excessive, but so be it.
         .binary_search_by(|&i| {
-            let mut next: VecDeque<(u32, Vector2<i32>)> = VecDeque::new();
-            let mut visited: HashSet<Vector2<i32>> = HashSet::new();
-            next.push_back((0, start));
-            visited.insert(start);
-
-            const OFFSETS: &[Vector2<i32>] = &[
-                Vector2::new(1, 0),
-                Vector2::new(0, 1),
-                Vector2::new(-1, 0),
-                Vector2::new(0, -1),
-            ];
-
-            'outer: while let Some((cost, current)) = next.pop_front() {
-                for offset in OFFSETS {
-                    let neighbour = current + offset;
-
-                    if visited.contains(&neighbour)
-                        || fall_locations[..=i].contains(&neighbour)
-                        || !(0..=size).contains(&neighbour.x)
-                        || !(0..=size).contains(&neighbour.y)
-                    {
-                        continue;
-                    }
-
-                    visited.insert(neighbour);
-                    next.push_back((cost + 1, neighbour));
-
-                    if neighbour == end {
-                        break 'outer;
-                    }
-                }
-            }
-
-            if next.pop_back().is_some() {
+            if shortest_path(&fall_locations[..=i], start, end, size).is_some() {
                 Ordering::Less
             } else {
                 Ordering::Greater
@@ -141,6 +73,35 @@ pub fn solve_(input: &[u8], size: i32, count_fallen: usize) -> (String, String)
     (common::from_option(part1), common::from_option(part2))
 }
 
+/// Number of steps from `start` to `end` avoiding the `fallen` bytes.
+fn shortest_path(
+    fallen: &[Vector2<i32>],
+    start: Vector2<i32>,
+    end: Vector2<i32>,
+    size: i32,
+) -> Option<usize> {
+    const OFFSETS: &[Vector2<i32>] = &[
+        Vector2::new(1, 0),
+        Vector2::new(0, 1),
+        Vector2::new(-1, 0),
+        Vector2::new(0, -1),
+    ];
+
+    let fallen: HashSet<Vector2<i32>> = fallen.iter().copied().collect();
+    let neighbours = |current: &Vector2<i32>| {
+        OFFSETS
+            .iter()
+            .map(|offset| current + offset)
+            .filter(|neighbour| {
+                !fallen.contains(neighbour)
+                    && (0..=size).contains(&neighbour.x)
+                    && (0..=size).contains(&neighbour.y)
+            })
+            .collect::<Vec<_>>()
+    };
+    search::bfs([start], neighbours, |&current| current == end).goal_cost()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
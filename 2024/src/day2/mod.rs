@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 2,
     name: "Day 2: Red-Nosed Reports",
     input: std::include_bytes!("input"),
     solve: self::solve,
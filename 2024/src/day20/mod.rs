@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 20,
     name: "Day 20: Race Condition",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 22,
     name: "Day 22: Monkey Market",
     input: std::include_bytes!("input"),
     solve: self::solve,
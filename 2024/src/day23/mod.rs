@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 23,
     name: "Day 23: LAN Party",
     input: std::include_bytes!("input"),
     solve: self::solve,
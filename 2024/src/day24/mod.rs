@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 24,
     name: "Day 24: Crossed Wires",
     input: std::include_bytes!("input"),
     solve: self::solve,
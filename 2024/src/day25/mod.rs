@@ -2,6 +2,8 @@ use itertools::Itertools;
 use nalgebra::Vector5;
 
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 25,
     name: "Day 25: Code Chronicle",
     input: std::include_bytes!("input"),
     solve: self::solve,
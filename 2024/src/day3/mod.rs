@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 3,
     name: "Day 3: Mull It Over",
     input: std::include_bytes!("input"),
     solve: self::solve,
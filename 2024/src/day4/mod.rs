@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 4,
     name: "Day 4: Ceres Search",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 5,
     name: "Day 5: Print Queue",
     input: std::include_bytes!("input"),
     solve: self::solve,
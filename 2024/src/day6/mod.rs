@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 6,
     name: "Day 6: Guard Gallivant",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -6,45 +8,14 @@ pub const SOLUTION: common::Solution = common::Solution {
 
 use std::collections::{HashMap, HashSet};
 
+use common::geometry::{Direction, Point};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Wall,
     Exit,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-impl Direction {
-    fn offset(&self) -> (i32, i32) {
-        match self {
-            Direction::North => (0, -1),
-            Direction::East => (1, 0),
-            Direction::South => (0, 1),
-            Direction::West => (-1, 0),
-        }
-    }
-
-    fn apply(&self, v: (i32, i32)) -> (i32, i32) {
-        let offset = self.offset();
-        (v.0 + offset.0, v.1 + offset.1)
-    }
-
-    fn rot_right(&self) -> Direction {
-        match self {
-            Direction::North => Direction::East,
-            Direction::East => Direction::South,
-            Direction::South => Direction::West,
-            Direction::West => Direction::North,
-        }
-    }
-}
-
 pub fn solve(input: &[u8]) -> (String, String) {
     let input = String::from_utf8_lossy(input);
     let input = input.as_ref();
@@ -90,11 +61,11 @@ pub fn solve(input: &[u8]) -> (String, String) {
     visited.insert(pos);
 
     loop {
-        let new_pos = dir.apply(pos);
+        let new_pos: (i32, i32) = (Point::from(pos) + dir).into();
 
         match map.get(&new_pos) {
             Some(Tile::Wall) => {
-                dir = dir.rot_right();
+                dir = dir.cw();
             }
             Some(Tile::Exit) => break,
             None => {
@@ -116,11 +87,11 @@ pub fn solve(input: &[u8]) -> (String, String) {
     visited_dir.insert((pos, dir));
 
     loop {
-        let new_pos = dir.apply(pos);
+        let new_pos: (i32, i32) = (Point::from(pos) + dir).into();
 
         match map.get(&new_pos) {
             Some(Tile::Wall) => {
-                dir = dir.rot_right();
+                dir = dir.cw();
             }
             Some(Tile::Exit) => break,
             None => {
@@ -128,12 +99,12 @@ pub fn solve(input: &[u8]) -> (String, String) {
                     // Try placing an obstruction in front.
                     let obstruction = new_pos;
                     let mut pos = pos;
-                    let mut dir = dir.rot_right();
+                    let mut dir = dir.cw();
                     let mut visited: HashSet<(i32, i32)> = visited.clone();
                     let mut visited_dir: HashSet<((i32, i32), Direction)> = visited_dir.clone();
 
                     loop {
-                        let new_pos = dir.apply(pos);
+                        let new_pos: (i32, i32) = (Point::from(pos) + dir).into();
 
                         if visited_dir.contains(&(new_pos, dir)) {
                             // We are looping.
@@ -143,11 +114,11 @@ pub fn solve(input: &[u8]) -> (String, String) {
 
                         match map.get(&new_pos) {
                             Some(Tile::Wall) => {
-                                dir = dir.rot_right();
+                                dir = dir.cw();
                             }
                             Some(Tile::Exit) => break,
                             None if new_pos == obstruction => {
-                                dir = dir.rot_right();
+                                dir = dir.cw();
                             }
                             None => {
                                 pos = new_pos;
@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 7,
     name: "Day 7: Bridge Repair",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -8,6 +10,7 @@ mod parse;
 
 use std::ops::{Add, Mul};
 
+use common_v2::progress;
 use itertools::Itertools;
 
 fn concat_i64(lhs: i64, rhs: i64) -> i64 {
@@ -18,11 +21,13 @@ fn concat_i64(lhs: i64, rhs: i64) -> i64 {
 /// Brute forces all permutations until a matching permutation is found or all
 /// possible permutations are exhausted.
 fn brute_force(
+    part: u8,
     equations: &[(i64, Vec<i64>)],
     ops: impl Iterator<Item = fn(i64, i64) -> i64> + Clone,
 ) -> i64 {
+    progress::status_now(format_args!("part {part}"));
     let mut sum: i64 = 0;
-    for (target, args) in equations.iter() {
+    for (i, (target, args)) in equations.iter().enumerate() {
         // Permutations with replacement.
         let operation_combinations =
             itertools::repeat_n(ops.clone(), args.len() - 1).multi_cartesian_product();
@@ -32,7 +37,7 @@ fn brute_force(
         for combination in operation_combinations {
             let result = args_iter
                 .clone()
-                .zip(combination.into_iter())
+                .zip(combination)
                 .fold(*initial, |acc, (arg, op)| op(acc, *arg));
 
             if *target == result {
@@ -40,6 +45,7 @@ fn brute_force(
                 break;
             }
         }
+        progress::count(i + 1, equations.len());
     }
 
     sum
@@ -52,10 +58,15 @@ pub fn solve(input: &[u8]) -> (String, String) {
     let (_, equations) = parse::parse_reports(input).expect("Input should be valid");
 
     // Part 1
-    let part1: i64 = brute_force(&equations, [<i64 as Add>::add, Mul::<i64>::mul].into_iter());
+    let part1: i64 = brute_force(
+        1,
+        &equations,
+        [<i64 as Add>::add, Mul::<i64>::mul].into_iter(),
+    );
 
     // Part 2
     let part2: i64 = brute_force(
+        2,
         &equations,
         [<i64 as Add>::add, Mul::<i64>::mul, concat_i64].into_iter(),
     );
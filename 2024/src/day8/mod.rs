@@ -1,4 +1,6 @@
 pub const SOLUTION: common::Solution = common::Solution {
+    year: 2024,
+    day: 8,
     name: "Day 8: Resonant Collinearity",
     input: std::include_bytes!("input"),
     solve: self::solve,
@@ -3,48 +3,84 @@ use common_v2::prelude::*;
 pub struct Solution;
 
 pub struct State {
-    rotations: Box<[i32]>,
+    /// Clicks turned, negative to the left. Wide enough that any `u32` offset
+    /// can be negated and added to a position.
+    rotations: Box<[i64]>,
+    /// Dial position before each rotation and after the last one.
+    positions: Memo<Box<[i64]>>,
+}
+
+impl State {
+    fn positions(&self) -> &[i64] {
+        self.positions.get_or_compute(|| {
+            let mut positions = Vec::with_capacity(self.rotations.len() + 1);
+            let mut position = 50;
+            positions.push(position);
+            for rotation in self.rotations.iter() {
+                position = (position + rotation).rem_euclid(100);
+                positions.push(position);
+            }
+            positions.into_boxed_slice()
+        })
+    }
 }
 
 impl Solver for Solution {
+    const YEAR: u32 = 2025;
+    const DAY: u32 = 1;
+
     fn title() -> &'static str {
-        return "Day 1: Secret Entrance";
+        "Day 1: Secret Entrance"
     }
 
     fn input() -> &'static [u8] {
-        static INPUT: &'static [u8] = std::include_bytes!("input");
-        return &INPUT;
+        static INPUT: &[u8] = std::include_bytes!("input");
+        INPUT
     }
 
-    fn initial(input: &[u8]) -> Box<dyn PartSolver + 'static> {
-        let input = String::from_utf8_lossy(input);
+    fn initial(input: &[u8]) -> ParseResult {
+        let text = std::str::from_utf8(input)
+            .map_err(|err| ParseError::new((err.valid_up_to(), 1), "input is not valid UTF-8"))?;
         let mut rotations: Vec<_> = Vec::new();
-        for line in input.lines() {
-            let (direction, offset) = line.split_at(1);
-            let offset: i32 = offset
-                .parse()
-                .expect("Rotation offset should be an integer.");
+        for line in text.lines() {
+            let (direction, offset) = line.split_at_checked(1).ok_or_else(|| {
+                ParseError::at(input, line.as_bytes(), "expected a rotation")
+                    .with_help("rotations look like 'L68' or 'R14'")
+            })?;
+            let offset: u32 = offset.parse().map_err(|_| {
+                ParseError::at(
+                    input,
+                    offset.as_bytes(),
+                    "rotation offset should be a non-negative integer",
+                )
+            })?;
+            let offset = i64::from(offset);
             rotations.push(match direction {
                 "L" => -offset,
                 "R" => offset,
-                c => panic!("Expected direction to be L or R, got '{c}'."),
+                c => {
+                    return Err(ParseError::at(
+                        input,
+                        c.as_bytes(),
+                        "expected direction to be L or R",
+                    )
+                    .with_label(format!("got '{c}'")));
+                }
             });
         }
 
-        Box::new(State {
+        Ok(Box::new(State {
             rotations: rotations.into_boxed_slice(),
-        })
+            positions: Memo::new(),
+        }))
     }
 }
 
 impl PartSolver for State {
     fn part1(&self) -> Output {
-        let mut position = 50;
         let mut password = 0;
 
-        for rotation in self.rotations.iter() {
-            position += rotation;
-            position = position.rem_euclid(100);
+        for &position in self.positions()[1..].iter() {
             if position == 0 {
                 password += 1;
             }
@@ -54,10 +90,10 @@ impl PartSolver for State {
     }
 
     fn part2(&self) -> Output {
-        let mut position = 50;
         let mut password = 0;
 
-        for &rotation in self.rotations.iter() {
+        for (&rotation, &start) in self.rotations.iter().zip(self.positions()) {
+            let mut position = start;
             let step = rotation.signum();
             for _ in 0..rotation.abs() {
                 position = (position + step).rem_euclid(100);
@@ -110,4 +146,23 @@ L82",
     );
 
     solution!(p2, p2_solution, 6412);
+
+    #[test]
+    fn invalid_direction() {
+        let input = b"L68\nX30\n";
+        let Err(err) = Solution::initial(input) else {
+            panic!("input should be invalid");
+        };
+        assert_eq!(err.message(), "expected direction to be L or R");
+        assert_eq!(err.span(), (4, 1).into());
+    }
+
+    #[test]
+    fn large_offsets() {
+        let state = Solution::initial(b"L4294967295\nL4294967295\nR40\n").unwrap();
+        assert_eq!(state.part1(), 1.into());
+
+        let state = Solution::initial(b"L1000050\nR1000000\n").unwrap();
+        assert_eq!(state.part2(), 20001.into());
+    }
 }
@@ -10,20 +10,32 @@ pub struct State {
 }
 
 impl Solver for Solution {
+    // Not a real puzzle, so never registered.
+    const YEAR: u32 = 2025;
+    const DAY: u32 = 0;
+
     fn title() -> &'static str {
-        return "Day X: Snowy Mountain Monkeys";
+        "Day X: Snowy Mountain Monkeys"
     }
 
     fn input() -> &'static [u8] {
-        static INPUT: &'static [u8] = std::include_bytes!("input");
-        return &INPUT;
+        static INPUT: &[u8] = std::include_bytes!("input");
+        INPUT
     }
 
-    fn initial(input: &[u8]) -> Box<dyn PartSolver + 'static> {
-        let data = serde_json::from_slice::<Value>(input)
-            .unwrap_or_else(|err| panic!("unable to parse json: {}", err));
-
-        Box::new(State { data })
+    fn initial(input: &[u8]) -> ParseResult {
+        let data = serde_json::from_slice::<Value>(input).map_err(|err| {
+            // serde_json only knows the line and column of the error.
+            let offset: usize = input
+                .split_inclusive(|&b| b == b'\n')
+                .take(err.line().saturating_sub(1))
+                .map(<[u8]>::len)
+                .sum();
+            let offset = (offset + err.column().saturating_sub(1)).min(input.len());
+            ParseError::new((offset, 0), format!("unable to parse json: {err}"))
+        })?;
+
+        Ok(Box::new(State { data }))
     }
 }
 
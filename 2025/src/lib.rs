@@ -0,0 +1,17 @@
+pub mod day1;
+mod day_ex1;
+mod day_ex2;
+
+use common_v2::DynSolver;
+
+pub const SOLUTIONS: &[DynSolver] = &[DynSolver::new::<day1::Solution>()];
+
+const _: () = common_v2::check_solutions(2025, SOLUTIONS);
+
+#[cfg(test)]
+mod examples {
+    #[allow(unused_imports)]
+    use common_v2::{example_file, fuzz_file};
+
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
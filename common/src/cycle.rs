@@ -0,0 +1,186 @@
+//! Cycle detection, for skipping ahead in simulations that are asked to run
+//! for far too many steps.
+//!
+//! The state is changed in place by a `step` function, and identified by a
+//! `key`, which may be the whole state or anything that repeats along with
+//! it. [`find`] also returns a `value` of every state it saw, for looking up
+//! the value after many steps:
+//!
+//! ```
+//! use common::cycle;
+//!
+//! let (cycle, values) = cycle::find(&mut 3u32, |n| *n = *n * *n % 10, |&n| n, |&n| n);
+//! // 3, 9, 1, 1, ...
+//! assert_eq!((cycle.start, cycle.len), (2, 1));
+//! assert_eq!(values[cycle.equivalent(1_000_000_000)], 1);
+//! ```
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+/// States repeating every `len` steps, starting after `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`, at most
+    /// `start + len - 1`.
+    pub fn equivalent(&self, n: usize) -> usize {
+        n - self.cycles(n) * self.len
+    }
+
+    /// Number of whole cycles between [`Cycle::equivalent`] and step `n`.
+    pub fn cycles(&self, n: usize) -> usize {
+        n.saturating_sub(self.start) / self.len
+    }
+
+    /// Value after step `n`, for values that change by the same amount with
+    /// every cycle, like the height of a growing tower. `values` are the
+    /// values after each step from the initial state, at least up to step
+    /// `start + len`, as returned by [`find`].
+    ///
+    /// # Panics
+    ///
+    /// If there are too few `values`, or the number of cycles does not fit
+    /// in `V`.
+    pub fn extrapolate<V>(&self, n: usize, values: &[V]) -> V
+    where
+        V: Copy + Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+    {
+        if let Some(&value) = values.get(n) {
+            return value;
+        }
+        let end = self.start + self.len;
+        assert!(
+            values.len() > end,
+            "{} values given for a cycle ending at step {end}",
+            values.len()
+        );
+        let growth = values[end] - values[self.start];
+        let Ok(cycles) = V::try_from(self.cycles(n)) else {
+            panic!("{} cycles do not fit in the value", self.cycles(n));
+        };
+        values[self.equivalent(n)] + growth * cycles
+    }
+}
+
+/// Finds the cycle by remembering the key of every state. Stops with `state`
+/// after step `start + len`, which is the same as after step `start`.
+/// Returns the cycle along with the `value` of the state after every step up
+/// to that, starting with the initial state.
+pub fn find<S, K: Hash + Eq, V>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> V,
+) -> (Cycle, Vec<V>) {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut values = Vec::new();
+    for i in 0.. {
+        values.push(value(state));
+        match seen.entry(key(state)) {
+            Entry::Occupied(start) => {
+                let cycle = Cycle {
+                    start: *start.get(),
+                    len: i - start.get(),
+                };
+                return (cycle, values);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(i);
+            }
+        }
+        step(state);
+    }
+    unreachable!("the steps ran out before finding a cycle")
+}
+
+/// Finds the cycle with Brent's algorithm, which only keeps two states at a
+/// time, at the cost of stepping about three times as often as [`find`].
+/// Useful when there are too many states before the cycle to remember them.
+pub fn brent<S: Clone, K: Eq>(
+    initial: &S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    // Find the length, with the tortoise waiting at powers of two for the
+    // hare to come around.
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = key(initial);
+    let mut hare = initial.clone();
+    step(&mut hare);
+    while key(&hare) != tortoise {
+        if power == len {
+            tortoise = key(&hare);
+            power *= 2;
+            len = 0;
+        }
+        step(&mut hare);
+        len += 1;
+    }
+
+    // Find the start, with the hare staying `len` steps ahead until they
+    // meet.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..len {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    Cycle { start, len }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps of a sequence with 7 steps before repeating every 5 steps.
+    fn step(n: &mut u32) {
+        *n = if *n < 11 { *n + 1 } else { 7 };
+    }
+
+    #[test]
+    fn both_agree() {
+        let expected = Cycle { start: 7, len: 5 };
+        assert_eq!(find(&mut 0, step, |&n| n, |_| ()).0, expected);
+        assert_eq!(brent(&0, step, |&n| n), expected);
+        assert_eq!(brent(&7, step, |&n| n), Cycle { start: 0, len: 5 });
+        let (cycle, _) = find(&mut 5, |_| {}, |&n| n, |_| ());
+        assert_eq!(cycle, Cycle { start: 0, len: 1 });
+        assert_eq!(brent(&5, |_| {}, |&n| n), Cycle { start: 0, len: 1 });
+    }
+
+    #[test]
+    fn extrapolate() {
+        // Counts the steps taken, but only keys on the number.
+        let (cycle, values) = find(
+            &mut (0, 0),
+            |(n, steps)| {
+                step(n);
+                *steps += 1;
+            },
+            |&(n, _)| n,
+            |&(_, steps)| steps as i64,
+        );
+
+        assert_eq!(values.len(), 13);
+        assert_eq!(cycle.equivalent(3), 3);
+        assert_eq!(cycle.equivalent(13), 8);
+        assert_eq!(cycle.cycles(13), 1);
+        for n in [0, 5, 12, 13, 100, 1_000_000_000_000] {
+            assert_eq!(cycle.extrapolate(n, &values), n as i64);
+        }
+    }
+}
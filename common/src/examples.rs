@@ -0,0 +1,272 @@
+//! Example tests loaded from files, generated by the build scripts of the
+//! solution crates.
+//!
+//! Each day can have an `examples` directory next to its `input`. Every
+//! `examples/<name>.txt` in it is an example input, and the answers it should
+//! give are in the sidecar file `examples/<name>.answers`:
+//!
+//! ```text
+//! part1: 11
+//! part2: 31
+//! ```
+//!
+//! Either part can be left out, for examples only given for one part. Empty
+//! lines and lines starting with `#` are ignored. A test named
+//! `examples::<day>::<part>_<name>` is generated for each answer.
+//!
+//! The newline most editors add at the end of a file is removed from the
+//! input, like the string literals given to `example!`.
+//!
+//! Every day with a non-empty `input` also gets an ignored fuzz test named
+//! `examples::<day>::fuzz`, see [`crate::fuzz`]. The panic messages the day
+//! documents for invalid input are listed in `panics.txt` next to the input,
+//! one substring of a message per line, with the same comments as the
+//! answers.
+//!
+//! The build script of a solution crate calls [`generate`], and its `lib.rs`
+//! includes the generated tests next to the `example_file!` and `fuzz_file!`
+//! macros of the common crate the days use:
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod examples {
+//!     #[allow(unused_imports)]
+//!     use common::{example_file, fuzz_file};
+//!
+//!     include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+//! }
+//! ```
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single example with the answers it should give.
+#[derive(Debug, PartialEq, Eq)]
+struct Example {
+    name: String,
+    input: String,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Generates `$OUT_DIR/examples.rs` with the example and fuzz tests for the
+/// days in the `src` directory of the crate being built.
+pub fn generate() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("should be run by cargo");
+    let out_dir = std::env::var("OUT_DIR").expect("should be run by cargo");
+    let src = Path::new(&manifest_dir).join("src");
+
+    println!("cargo::rerun-if-changed={}", src.display());
+    let code = generate_code(&src).unwrap_or_else(|err| panic!("{err}"));
+    fs::write(Path::new(&out_dir).join("examples.rs"), code)
+        .expect("should be able to write examples.rs");
+}
+
+/// Code of the test modules for the days in `src`.
+fn generate_code(src: &Path) -> Result<String, String> {
+    let mut days: Vec<(String, Vec<Example>, Option<Vec<String>>)> = Vec::new();
+    for entry in read_dir(src)? {
+        let day = file_name(&entry);
+        if !entry.is_dir() || !day.starts_with("day") {
+            continue;
+        }
+        let examples = read_examples(&entry.join("examples"))?;
+        let panics = read_panics(&entry)?;
+        if !examples.is_empty() || panics.is_some() {
+            days.push((day, examples, panics));
+        }
+    }
+
+    let mut code = String::new();
+    for (day, examples, panics) in days {
+        writeln!(code, "pub mod {day} {{").unwrap();
+        writeln!(code, "    use super::*;").unwrap();
+        for example in examples {
+            let parts = [("p1", example.part1), ("p2", example.part2)];
+            for (part, answer) in parts {
+                if let Some(answer) = answer {
+                    writeln!(
+                        code,
+                        "    example_file!({part}, {day}, {part}_{}, {:?}, {answer:?});",
+                        identifier(&example.name),
+                        example.input,
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        if let Some(panics) = panics {
+            write!(code, "    fuzz_file!({day}").unwrap();
+            for panic in panics {
+                write!(code, ", {panic:?}").unwrap();
+            }
+            writeln!(code, ");").unwrap();
+        }
+        writeln!(code, "}}").unwrap();
+    }
+    Ok(code)
+}
+
+/// Documented panic messages of the day in `dir`, or `None` if it has no
+/// input to fuzz yet.
+fn read_panics(dir: &Path) -> Result<Option<Vec<String>>, String> {
+    let input = fs::metadata(dir.join("input"));
+    if !input.is_ok_and(|input| input.is_file() && input.len() > 0) {
+        return Ok(None);
+    }
+
+    let path = dir.join("panics.txt");
+    if !path.is_file() {
+        return Ok(Some(Vec::new()));
+    }
+    let text = fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    Ok(Some(
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_owned)
+            .collect(),
+    ))
+}
+
+/// Examples in the `examples` directory `dir`, sorted by name. A missing
+/// directory has no examples.
+fn read_examples(dir: &Path) -> Result<Vec<Example>, String> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut examples = Vec::new();
+    for path in read_dir(dir)? {
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let input = fs::read_to_string(&path)
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        let input = input
+            .strip_suffix('\n')
+            .map(|input| input.strip_suffix('\r').unwrap_or(input))
+            .unwrap_or(&input);
+        let answers_path = path.with_extension("answers");
+        let answers = fs::read_to_string(&answers_path)
+            .map_err(|err| format!("failed to read {}: {err}", answers_path.display()))?;
+        let (part1, part2) =
+            parse_answers(&answers).map_err(|err| format!("{}: {err}", answers_path.display()))?;
+
+        examples.push(Example {
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            input: input.to_owned(),
+            part1,
+            part2,
+        });
+    }
+    Ok(examples)
+}
+
+/// Answers for part 1 and 2 in the contents of an `.answers` file.
+fn parse_answers(text: &str) -> Result<(Option<String>, Option<String>), String> {
+    let (mut part1, mut part2) = (None, None);
+    for (row, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (slot, answer) = match line.split_once(':').map(|(key, value)| (key.trim(), value)) {
+            Some(("part1", answer)) => (&mut part1, answer),
+            Some(("part2", answer)) => (&mut part2, answer),
+            _ => {
+                return Err(format!(
+                    "line {}: expected 'part1: <answer>' or 'part2: <answer>'",
+                    row + 1
+                ));
+            }
+        };
+        if slot.replace(answer.trim().to_owned()).is_some() {
+            return Err(format!("line {}: answer given twice", row + 1));
+        }
+    }
+    Ok((part1, part2))
+}
+
+/// Entries of `dir` sorted by name.
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|err| format!("failed to read {}: {err}", dir.display()))?;
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("failed to read {}: {err}", dir.display()))?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Turns an example name into a valid part of a function name.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        let text = "# From the puzzle text\npart1: 11\n\npart2 :  FECZELHE \n";
+        assert_eq!(
+            parse_answers(text),
+            Ok((Some("11".to_owned()), Some("FECZELHE".to_owned())))
+        );
+        assert_eq!(parse_answers("part2: 4"), Ok((None, Some("4".to_owned()))));
+        assert!(parse_answers("part3: 4").is_err());
+        assert!(parse_answers("part1: 4\npart1: 5").is_err());
+    }
+
+    #[test]
+    fn generated_tests() {
+        let src = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let examples = src.join("day3").join("examples");
+        fs::create_dir_all(&examples).unwrap();
+        fs::create_dir_all(src.join("day4")).unwrap();
+        fs::write(examples.join("Larger one.txt"), "1 2\n").unwrap();
+        fs::write(examples.join("Larger one.answers"), "part2: 3\n").unwrap();
+        fs::write(examples.join("notes.md"), "").unwrap();
+        fs::write(src.join("day4").join("input"), "").unwrap();
+        fs::create_dir_all(src.join("day5")).unwrap();
+        fs::write(src.join("day5").join("input"), "1 2 3\n").unwrap();
+        fs::write(
+            src.join("day5").join("panics.txt"),
+            "# From expect\ninput should be valid\n\n",
+        )
+        .unwrap();
+
+        let code = generate_code(&src);
+        fs::remove_dir_all(&src).unwrap();
+
+        assert_eq!(
+            code.unwrap(),
+            format!(
+                "pub mod day3 {{\n    use super::*;\n    \
+                 example_file!(p2, day3, p2_larger_one, \"1 2\", \"3\");\n}}\n\
+                 pub mod day5 {{\n    use super::*;\n    \
+                 fuzz_file!(day5, \"input should be valid\");\n}}\n"
+            )
+        );
+    }
+}
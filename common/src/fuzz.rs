@@ -0,0 +1,458 @@
+//! Fuzz testing of solutions with randomly mutated inputs.
+//!
+//! [`check`] feeds mutated versions of a day's input to the solution. Every
+//! input must either be solved, rejected with an error or make the solution
+//! panic with one of the documented messages. Undocumented panics, inputs
+//! that take far longer than the real input and crashes like stack overflows
+//! fail the test, after shrinking the offending input to something small.
+//!
+//! Each input is run in a child process, which is the test binary running
+//! only the calling test. This is the only way to survive a stack overflow or
+//! stop a solution stuck in an infinite loop.
+//!
+//! Every day with an input gets a fuzz test from the build script of its
+//! crate, see [`crate::examples`]. Running hundreds of processes takes a
+//! while, so these are ignored by default. Run them with
+//! `cargo test fuzz -- --ignored`. The number of cases and the seed can be
+//! set with the `AOC_FUZZ_CASES` and `AOC_FUZZ_SEED` environment variables.
+
+use std::io::{Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Function solving or parsing an input, returning an error for input it
+/// rejects.
+pub type Target = fn(&[u8]) -> Result<(), String>;
+
+const CHILD_ENV: &str = "AOC_FUZZ_CHILD";
+const RESULT_MARKER: &str = "AOC_FUZZ_RESULT ";
+
+const DEFAULT_CASES: usize = 100;
+const DEFAULT_SEED: u64 = 0x5eed_ab1e;
+
+/// Inputs running this many times longer than the real input are taken to
+/// never finish.
+const HANG_FACTOR: u32 = 20;
+const MIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Stack size of the thread solving the input. Some solutions recurse deeply
+/// on real inputs, so only runaway recursion should overflow it.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Upper bounds on the work spent shrinking a failing input.
+const SHRINK_RUNS: usize = 300;
+const SHRINK_TIME: Duration = Duration::from_secs(60);
+
+/// What happened when solving a single input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Solved,
+    Rejected(String),
+    Panicked(String),
+    Hung,
+    Crashed(String),
+}
+
+impl Outcome {
+    fn is_failure(&self, documented: &[&str]) -> bool {
+        match self {
+            Outcome::Solved | Outcome::Rejected(_) => false,
+            Outcome::Panicked(message) => !documented.iter().any(|doc| message.contains(doc)),
+            Outcome::Hung | Outcome::Crashed(_) => true,
+        }
+    }
+
+    /// Whether both are the same kind of failure, so shrinking does not
+    /// wander off to a different bug.
+    fn same_kind(&self, other: &Outcome) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Outcome::Solved => "solved".to_owned(),
+            Outcome::Rejected(err) => format!("rejected: {err}"),
+            Outcome::Panicked(message) => format!("panicked with '{message}'"),
+            Outcome::Hung => "did not finish in time".to_owned(),
+            Outcome::Crashed(output) => format!("crashed:\n{output}"),
+        }
+    }
+}
+
+/// Name of a test as given to the test harness, from the `module_path!()`
+/// of the test module and the name of the test function.
+pub fn test_name(module_path: &str, test: &str) -> String {
+    match module_path.split_once("::") {
+        Some((_crate, path)) => format!("{path}::{test}"),
+        None => test.to_owned(),
+    }
+}
+
+/// Fuzzes `target` with mutations of `input`. `test` is the full name of the
+/// calling test, see [`test_name`], and `documented` lists substrings of the
+/// panic messages that are accepted for invalid input.
+pub fn check(test: &str, input: &[u8], target: Target, documented: &[&str]) {
+    if std::env::var_os(CHILD_ENV).is_some() {
+        run_child(target);
+        return;
+    }
+
+    let env = |name: &str| std::env::var(name).ok();
+    let cases = env("AOC_FUZZ_CASES").map_or(DEFAULT_CASES, |v| {
+        v.parse().expect("AOC_FUZZ_CASES should be a number")
+    });
+    let seed = env("AOC_FUZZ_SEED").map_or(DEFAULT_SEED, |v| {
+        v.parse().expect("AOC_FUZZ_SEED should be a number")
+    });
+
+    let mut runner = Runner {
+        exe: std::env::current_exe().expect("should know the test binary"),
+        test: test.to_owned(),
+        timeout: Duration::MAX,
+    };
+
+    let start = Instant::now();
+    let outcome = runner.run(input);
+    if outcome != Outcome::Solved {
+        panic!(
+            "the unmodified input was not solved, it {}",
+            outcome.describe()
+        );
+    }
+    runner.timeout = (start.elapsed() * HANG_FACTOR).max(MIN_TIMEOUT);
+
+    let mut failures = Vec::new();
+    for case in 0..cases {
+        let mutated = mutate(input, seed, case);
+        let outcome = runner.run(&mutated);
+        if outcome.is_failure(documented) {
+            failures.push((case, mutated, outcome));
+        }
+    }
+
+    let Some((case, mutated, outcome)) = failures.first() else {
+        return;
+    };
+
+    let (shrunk, shrunk_outcome) = runner.shrink(mutated.clone(), outcome, documented);
+    panic!(
+        "{} of {cases} mutated inputs failed, the first was case {case} which {}\n\
+         shrunk to {} bytes that {}:\n{:?}\n\
+         reproduce with AOC_FUZZ_SEED={seed}",
+        failures.len(),
+        outcome.describe(),
+        shrunk.len(),
+        shrunk_outcome.describe(),
+        String::from_utf8_lossy(&shrunk),
+    );
+}
+
+/// Runs inside the child process, solving the input from standard input and
+/// printing the outcome for the parent.
+fn run_child(target: Target) {
+    let mut input = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut input)
+        .expect("should read input from parent");
+
+    let result = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || panic::catch_unwind(AssertUnwindSafe(|| target(&input))))
+        .expect("should spawn solving thread")
+        .join()
+        .expect("panics should be caught");
+
+    let outcome = match result {
+        Ok(Ok(())) => "solved".to_owned(),
+        Ok(Err(err)) => format!("rejected\t{err}"),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            format!("panicked\t{message}")
+        }
+    };
+    // The test harness may already have written to the same line.
+    println!("\n{RESULT_MARKER}{}", outcome.replace('\n', " "));
+}
+
+/// Runs single inputs in child processes.
+struct Runner {
+    exe: PathBuf,
+    test: String,
+    timeout: Duration,
+}
+
+impl Runner {
+    fn run(&self, input: &[u8]) -> Outcome {
+        let mut child = Command::new(&self.exe)
+            .args([
+                &self.test,
+                "--exact",
+                "--include-ignored",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env(CHILD_ENV, "1")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("should spawn test binary");
+
+        // Pipes are fed and drained on their own threads so a child writing a
+        // lot of output can not block.
+        let mut stdin = child.stdin.take().unwrap();
+        let input = input.to_vec();
+        thread::spawn(move || stdin.write_all(&input));
+        let read_all = |mut pipe: Box<dyn Read + Send>| {
+            thread::spawn(move || {
+                let mut buf = Vec::new();
+                pipe.read_to_end(&mut buf).ok();
+                String::from_utf8_lossy(&buf).into_owned()
+            })
+        };
+        let stdout = read_all(Box::new(child.stdout.take().unwrap()));
+        let stderr = read_all(Box::new(child.stderr.take().unwrap()));
+
+        let deadline = Instant::now().checked_add(self.timeout);
+        let status = loop {
+            if let Some(status) = child.try_wait().expect("should wait for child") {
+                break status;
+            }
+            if deadline.is_some_and(|deadline| Instant::now() > deadline) {
+                child.kill().ok();
+                child.wait().ok();
+                return Outcome::Hung;
+            }
+            thread::sleep(Duration::from_millis(2));
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        let result = stdout
+            .lines()
+            .find_map(|line| Some(line.split_once(RESULT_MARKER)?.1));
+        match result.map(|result| result.split_once('\t').unwrap_or((result, ""))) {
+            Some(("solved", _)) => Outcome::Solved,
+            Some(("rejected", err)) => Outcome::Rejected(err.to_owned()),
+            Some((_, message)) => Outcome::Panicked(message.to_owned()),
+            None if status.success() => {
+                panic!("test '{}' did not run in the child process", self.test)
+            }
+            None => {
+                let stderr = stderr.join().unwrap_or_default();
+                let lines: Vec<&str> = stderr.lines().collect();
+                let tail = lines[lines.len().saturating_sub(5)..].join("\n");
+                Outcome::Crashed(format!("{status}\n{tail}"))
+            }
+        }
+    }
+
+    /// Removes as much of a failing input as possible while it still fails
+    /// the same way, first whole lines and then single bytes.
+    fn shrink(
+        &self,
+        mut input: Vec<u8>,
+        outcome: &Outcome,
+        documented: &[&str],
+    ) -> (Vec<u8>, Outcome) {
+        let start = Instant::now();
+        let mut runs = 0;
+        let mut last = outcome.clone();
+
+        for by_line in [true, false] {
+            let mut chunk = pieces(&input, by_line).len() / 2;
+            while chunk > 0 {
+                let mut i = 0;
+                loop {
+                    let parts = pieces(&input, by_line);
+                    if i >= parts.len() || runs >= SHRINK_RUNS || start.elapsed() > SHRINK_TIME {
+                        break;
+                    }
+
+                    let candidate: Vec<u8> = parts
+                        .iter()
+                        .enumerate()
+                        .filter(|&(j, _)| j < i || j >= i + chunk)
+                        .flat_map(|(_, part)| part.iter().copied())
+                        .collect();
+                    runs += 1;
+                    let result = self.run(&candidate);
+                    if result.is_failure(documented) && result.same_kind(outcome) {
+                        input = candidate;
+                        last = result;
+                    } else {
+                        i += chunk;
+                    }
+                }
+                chunk /= 2;
+            }
+        }
+
+        (input, last)
+    }
+}
+
+/// Splits the input into lines, keeping the line endings, or single bytes.
+fn pieces(input: &[u8], by_line: bool) -> Vec<&[u8]> {
+    if by_line {
+        input.split_inclusive(|&b| b == b'\n').collect()
+    } else {
+        input.chunks(1).collect()
+    }
+}
+
+/// Small, fast pseudo random number generator (SplitMix64), so that cases
+/// can be reproduced from the seed alone.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in `0..n`, or 0 if `n` is 0.
+    fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            0
+        } else {
+            (self.next() % n as u64) as usize
+        }
+    }
+}
+
+/// Bytes that tend to matter to parsers.
+const INTERESTING: &[u8] = b"0123456789-+ \n\t,.:;#=()[]{}<>xXaZ\0\xff";
+
+/// Numbers that tend to break solutions.
+const NUMBERS: &[&[u8]] = &[
+    b"0",
+    b"-1",
+    b"65536",
+    b"4294967296",
+    b"99999999999999999999999",
+];
+
+/// Applies one to four random mutations to `input`, picked by the seed and
+/// case number.
+fn mutate(input: &[u8], seed: u64, case: usize) -> Vec<u8> {
+    let mut rng = Rng(seed ^ (case as u64).wrapping_mul(0x2545_f491_4f6c_dd1d));
+    let mut data = input.to_vec();
+
+    for _ in 0..1 + rng.below(4) {
+        let len = data.len();
+        let pos = rng.below(len + 1);
+        match rng.below(7) {
+            // Replace a byte.
+            0 if pos < len => data[pos] = INTERESTING[rng.below(INTERESTING.len())],
+            // Insert a byte.
+            1 => data.insert(pos, INTERESTING[rng.below(INTERESTING.len())]),
+            // Remove a few bytes.
+            2 => {
+                let end = (pos + 1 + rng.below(16)).min(len);
+                data.drain(pos.min(end)..end);
+            }
+            // Truncate.
+            3 => data.truncate(pos),
+            // Duplicate or remove a line.
+            4 | 5 => {
+                let mut lines: Vec<Vec<u8>> =
+                    pieces(&data, true).iter().map(|l| l.to_vec()).collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                let line = rng.below(lines.len());
+                if rng.below(2) == 0 {
+                    lines.insert(line, lines[line].clone());
+                } else {
+                    lines.remove(line);
+                }
+                data = lines.concat();
+            }
+            // Replace a number with an extreme one.
+            _ => {
+                let Some(start) = (pos..len).find(|&i| data[i].is_ascii_digit()) else {
+                    continue;
+                };
+                let end = (start..len)
+                    .find(|&i| !data[i].is_ascii_digit())
+                    .unwrap_or(len);
+                let number = NUMBERS[rng.below(NUMBERS.len())];
+                data.splice(start..end, number.iter().copied());
+            }
+        }
+    }
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutations_are_reproducible() {
+        let input = b"123 456\n789 10\n";
+        for case in 0..20 {
+            assert_eq!(mutate(input, 1, case), mutate(input, 1, case));
+        }
+        assert!((0..20).any(|case| mutate(input, 1, case) != input));
+    }
+
+    #[test]
+    fn strip_crate_from_test_name() {
+        assert_eq!(
+            test_name("solutions_2015::day7::tests", "fuzz_parser"),
+            "day7::tests::fuzz_parser"
+        );
+    }
+
+    fn reject_hash(input: &[u8]) -> Result<(), String> {
+        if input.contains(&b'#') {
+            return Err("no # allowed".to_owned());
+        }
+        if input.contains(&b'!') {
+            panic!("found a '!'");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fuzz_clean_errors() {
+        check(
+            &test_name(module_path!(), "fuzz_clean_errors"),
+            b"1 2 3\n4 5 6\n",
+            reject_hash,
+            &[],
+        );
+    }
+
+    #[test]
+    fn shrink_failing_input() {
+        let runner = Runner {
+            exe: std::env::current_exe().unwrap(),
+            test: test_name(module_path!(), "shrink_target"),
+            timeout: Duration::from_secs(10),
+        };
+        let outcome = Outcome::Panicked("found a '!'".to_owned());
+        let (shrunk, _) = runner.shrink(b"abc\nd!e\nfgh\n".to_vec(), &outcome, &[]);
+        assert_eq!(shrunk, b"!");
+    }
+
+    #[test]
+    fn shrink_target() {
+        // Only runs as the child of `shrink_failing_input`.
+        if std::env::var_os(CHILD_ENV).is_some() {
+            run_child(reject_hash);
+        }
+    }
+}
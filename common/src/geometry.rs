@@ -0,0 +1,487 @@
+//! Directions and points on a two-dimensional map.
+//!
+//! Like [`Grid`](crate::grid::Grid), positions are `(x, y)` with `y` growing
+//! downwards, so [`Direction::North`] is `(0, -1)`.
+//!
+//! ```
+//! use common::geometry::{Direction, Point, Turn};
+//!
+//! let mut pos = Point::new(0, 0);
+//! let mut dir = Direction::try_from('^').unwrap();
+//! for turn in [Turn::Right, Turn::Straight, Turn::Left] {
+//!     dir = dir.turn(turn);
+//!     pos += dir;
+//! }
+//! assert_eq!(pos, Point::new(2, -1));
+//! assert_eq!(<(i32, i32)>::from(pos), (2, -1));
+//! ```
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// One of the four cardinal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A turn relative to the current [`Direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+    Straight,
+}
+
+/// One of the four cardinal or four ordinal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// A character that is not a direction or turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDirection(pub char);
+
+impl Display for InvalidDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDirection {}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The direction after turning right.
+    pub const fn cw(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// The direction after turning left.
+    pub const fn ccw(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub const fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub const fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.ccw(),
+            Turn::Right => self.cw(),
+            Turn::Around => self.opposite(),
+            Turn::Straight => self,
+        }
+    }
+
+    /// Whether the direction is east or west.
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    /// Whether the direction is north or south.
+    pub const fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Unit vector `(dx, dy)` pointing in the direction, as taken by
+    /// [`Grid::offset`](crate::grid::Grid::offset).
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// Unit vector pointing in the direction.
+    pub fn unit<T: From<i8>>(self) -> Point<T> {
+        let (dx, dy) = self.offset();
+        Point::new(T::from(dx as i8), T::from(dy as i8))
+    }
+
+    /// The arrow used for the direction in puzzle inputs, one of `^>v<`.
+    pub const fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+/// Parses an arrow `^v<>`, a letter `UDLR` or a compass point `NESW`.
+impl TryFrom<char> for Direction {
+    type Error = InvalidDirection;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::North),
+            '>' | 'R' | 'E' => Ok(Direction::East),
+            'v' | 'D' | 'S' => Ok(Direction::South),
+            '<' | 'L' | 'W' => Ok(Direction::West),
+            _ => Err(InvalidDirection(c)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = InvalidDirection;
+
+    fn try_from(c: u8) -> Result<Self, Self::Error> {
+        Direction::try_from(c as char)
+    }
+}
+
+/// Parses `L` or `R`.
+impl TryFrom<char> for Turn {
+    type Error = InvalidDirection;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(InvalidDirection(c)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Turn {
+    type Error = InvalidDirection;
+
+    fn try_from(c: u8) -> Result<Self, Self::Error> {
+        Turn::try_from(c as char)
+    }
+}
+
+impl Direction8 {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The direction an eighth of a turn to the right.
+    pub const fn cw(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn to the left.
+    pub const fn ccw(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The direction as a cardinal direction, if it is one.
+    pub const fn cardinal(self) -> Option<Direction> {
+        match self {
+            Direction8::North => Some(Direction::North),
+            Direction8::East => Some(Direction::East),
+            Direction8::South => Some(Direction::South),
+            Direction8::West => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Vector `(dx, dy)` pointing in the direction, one step along each axis
+    /// it moves along.
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Vector pointing in the direction, see [`Direction8::offset`].
+    pub fn unit<T: From<i8>>(self) -> Point<T> {
+        let (dx, dy) = self.offset();
+        Point::new(T::from(dx as i8), T::from(dy as i8))
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+/// Position or vector on a map.
+///
+/// Converts from and into `(x, y)` tuples, and with the `nalgebra` feature
+/// also nalgebra's `Vector2`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// The point one step away in direction `dir`. Only for signed
+    /// coordinates, see [`Point::checked_step`] for unsigned ones.
+    pub fn step(self, dir: Direction) -> Self
+    where
+        T: From<i8> + Add<Output = T>,
+    {
+        self + dir.unit()
+    }
+
+    /// The point one step away in direction `dir`, or `None` if it does not
+    /// fit in `T`, like stepping west from `x == 0` with unsigned
+    /// coordinates.
+    pub fn checked_step(self, dir: Direction) -> Option<Self>
+    where
+        T: TryFrom<isize> + TryInto<isize>,
+    {
+        let (dx, dy) = dir.offset();
+        let x: isize = self.x.try_into().ok()?;
+        let y: isize = self.y.try_into().ok()?;
+        Some(Point::new(
+            T::try_from(x.checked_add(dx)?).ok()?,
+            T::try_from(y.checked_add(dy)?).ok()?,
+        ))
+    }
+
+    /// The four points sharing an edge with this one, clockwise from north.
+    pub fn neighbours4(self) -> [Self; 4]
+    where
+        T: Copy + From<i8> + Add<Output = T>,
+    {
+        Direction::ALL.map(|dir| self + dir.unit())
+    }
+
+    /// The eight points sharing an edge or corner with this one, clockwise
+    /// from north.
+    pub fn neighbours8(self) -> [Self; 8]
+    where
+        T: Copy + From<i8> + Add<Output = T>,
+    {
+        Direction8::ALL.map(|dir| self + dir.unit())
+    }
+
+    /// Manhattan distance between the points.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: PartialOrd + Sub<Output = T> + Add<Output = T>,
+    {
+        // Written without `abs` so it also works for unsigned points.
+        let distance = |a: T, b: T| if a < b { b - a } else { a - b };
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar> From<nalgebra::Vector2<T>> for Point<T> {
+    fn from(vector: nalgebra::Vector2<T>) -> Self {
+        let [[x, y]] = vector.data.0;
+        Point { x, y }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::Scalar> From<Point<T>> for nalgebra::Vector2<T> {
+    fn from(point: Point<T>) -> Self {
+        nalgebra::Vector2::new(point.x, point.y)
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// Scales the vector by `rhs`.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Moves the point one step in the direction.
+impl<T: From<i8> + Add<Output = T>> Add<Direction> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self {
+        self + rhs.unit()
+    }
+}
+
+impl<T: Copy + From<i8> + Add<Output = T>> AddAssign<Direction> for Point<T> {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.cw().ccw(), dir);
+            assert_eq!(dir.cw().cw(), dir.opposite());
+            assert_eq!(dir.turn(Turn::Around), dir.opposite());
+            assert_eq!(dir.turn(Turn::Straight), dir);
+            assert_eq!(Direction8::from(dir).cw().cw(), dir.cw().into());
+            assert_eq!(Direction8::from(dir).cardinal(), Some(dir));
+            assert_eq!(dir.unit::<i32>() + dir.opposite().unit(), Point::new(0, 0));
+        }
+        assert_eq!(Direction8::North.ccw(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.opposite(), Direction8::NorthEast);
+        assert_eq!(Direction8::SouthWest.cardinal(), None);
+    }
+
+    #[test]
+    fn parsing() {
+        for (chars, dir) in ["^UN", ">RE", "vDS", "<LW"].iter().zip(Direction::ALL) {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(dir));
+            }
+            assert_eq!(Direction::try_from(dir.arrow()), Ok(dir));
+        }
+        assert_eq!(Direction::try_from(b'v'), Ok(Direction::South));
+        assert_eq!(Direction::try_from('V'), Err(InvalidDirection('V')));
+        assert_eq!(Turn::try_from(b'L'), Ok(Turn::Left));
+        assert_eq!(Turn::try_from('U'), Err(InvalidDirection('U')));
+    }
+
+    #[test]
+    fn points() {
+        let p = Point::new(3i64, -2);
+        assert_eq!(p + Direction::North, Point::new(3, -3));
+        assert_eq!(p.step(Direction::West), Point::new(2, -2));
+        assert_eq!(-p * 2, Point::new(-6, 4));
+        assert_eq!(p.manhattan(Point::new(-1, 1)), 7);
+        assert_eq!(Point::new(5u8, 1).manhattan(Point::new(2, 4)), 6);
+        assert_eq!(
+            Point::new(1.0, 1.0).neighbours4(),
+            [(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 1.0)].map(Point::from)
+        );
+        assert_eq!(Point::new(0i16, 0).neighbours8()[7], Point::new(-1, -1));
+        assert_eq!(<(i64, i64)>::from(p), (3, -2));
+        assert_eq!(p.to_string(), "(3, -2)");
+
+        let p = Point::new(0usize, 1);
+        assert_eq!(p.checked_step(Direction::North), Some(Point::new(0, 0)));
+        assert_eq!(p.checked_step(Direction::West), None);
+        assert_eq!(Point::new(255u8, 0).checked_step(Direction::East), None);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra() {
+        let v = nalgebra::Vector2::new(4, 7);
+        assert_eq!(Point::from(v), Point::new(4, 7));
+        assert_eq!(
+            nalgebra::Vector2::from(Point::from(v) + Direction::East),
+            nalgebra::Vector2::new(5, 7)
+        );
+    }
+}
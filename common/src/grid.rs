@@ -0,0 +1,418 @@
+//! Two-dimensional grid, as used by most puzzles with a map for input.
+//!
+//! Cells are stored row by row and addressed as `(x, y)`, with `x` the
+//! column counted from the left and `y` the row counted from the top.
+
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Direction, Direction8};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// A row is not as wide as the first one.
+    Ragged {
+        y: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A character is not a valid cell.
+    InvalidCell { x: usize, y: usize, c: char },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged { y, width, expected } => {
+                write!(f, "row {y} is {width} wide, expected {expected}")
+            }
+            GridError::InvalidCell { x, y, c } => {
+                write!(f, "invalid cell {c:?} at x={x}, y={y}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells do not fill rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a map with one character per cell and one row per line,
+    /// converting each character with `f`. Returns an error if the rows are
+    /// not all the same width or `f` returns `None`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (y, line) in input.lines().enumerate() {
+            let mut x = 0;
+            for c in line.chars() {
+                cells.push(f(c).ok_or(GridError::InvalidCell { x, y, c })?);
+                x += 1;
+            }
+            let expected = *width.get_or_insert(x);
+            if x != expected {
+                return Err(GridError::Ragged {
+                    y,
+                    width: x,
+                    expected,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` is inside the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.contains(x, y)
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Position of the cell `(x, y)` moved by `(dx, dy)`, if it is inside the
+    /// grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        self.contains(x, y).then_some((x, y))
+    }
+
+    /// Position of the cell next to `(x, y)` in direction `dir`, if it is
+    /// inside the grid.
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, dir.offset())
+    }
+
+    /// Positions of the up to four cells sharing an edge with `(x, y)`,
+    /// clockwise from up.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step((x, y), dir))
+    }
+
+    /// Positions of the up to eight cells sharing an edge or a corner with
+    /// `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset((x, y), dir.offset()))
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// If `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} outside grid of height {}",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics for a width of 0, which has no rows to give anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} outside grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Positions and cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Positions and mutable cells, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i % self.width, i / self.width))
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid with `(x, y)` moved to `(y, x)`, mirroring it along the diagonal
+    /// from the top left corner.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Grid turned a quarter clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Grid turned a quarter counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Grid of the given size, where `(x, y)` takes the cell at `source(x, y)`.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map keeping the characters as they are.
+    pub fn from_chars(input: &str) -> Result<Self, GridError> {
+        Grid::parse(input, Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        match self.get(x, y) {
+            Some(cell) => cell,
+            None => panic!("({x}, {y}) outside grid of {}x{}", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(x, y) {
+            Some(cell) => cell,
+            None => panic!("({x}, {y}) outside grid of {width}x{height}"),
+        }
+    }
+}
+
+/// Draws the grid with one line per row, without a newline after the last.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+#..
+.#.
+##.
+#..
+";
+
+    #[test]
+    fn parse() {
+        let grid =
+            Grid::parse(MAP, |c| (c == '#').then_some(1).or((c == '.').then_some(0))).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(1, 2)], 1);
+        assert_eq!(grid.get(3, 0), None);
+
+        assert_eq!(
+            Grid::from_chars("ab\r\ncd\r\n").unwrap().to_string(),
+            "ab\ncd"
+        );
+        assert_eq!(
+            Grid::from_chars("ab\nc\n"),
+            Err(GridError::Ragged {
+                y: 1,
+                width: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse("..\n.x", |c| (c == '.').then_some(())),
+            Err(GridError::InvalidCell { x: 1, y: 1, c: 'x' })
+        );
+        let empty = Grid::from_chars("").unwrap();
+        assert_eq!(
+            (empty.width(), empty.height(), empty.rows().count()),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) outside grid of 3x4")]
+    fn index_out_of_bounds() {
+        // Must not wrap around to the next row.
+        let _ = Grid::from_chars(MAP).unwrap()[(3, 0)];
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::from_chars(MAP).unwrap();
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(
+            grid.neighbours8(2, 3).collect::<Vec<_>>(),
+            [(2, 2), (1, 3), (1, 2)]
+        );
+        let lit = |(x, y)| grid[(x, y)] == '#';
+        assert_eq!(grid.neighbours8(1, 1).filter(|&p| lit(p)).count(), 3);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_chars(MAP).unwrap();
+        assert_eq!(grid.row(2), ['#', '#', '.']);
+        assert_eq!(grid.column(0).collect::<String>(), "#.##");
+        assert_eq!(grid.column(1).rev().collect::<String>(), ".##.");
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["#.##", ".##.", "...."]
+        );
+        assert_eq!(grid.position(|&c| c == '#'), Some((0, 0)));
+        assert_eq!(grid.iter().filter(|(_, c)| **c == '#').count(), 5);
+    }
+
+    #[test]
+    fn rotate_and_transpose() {
+        let grid = Grid::from_chars("abc\ndef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw().to_string(), "fed\ncba");
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
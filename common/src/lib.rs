@@ -1,13 +1,77 @@
+pub mod cycle;
+pub mod examples;
+pub mod fuzz;
+pub mod geometry;
+pub mod grid;
+pub mod ocr;
+pub mod search;
+
 use std::fmt::Display;
 
 pub type Solver = fn(&[u8]) -> (String, String);
 
 pub struct Solution<'a> {
+    pub year: u32,
+    pub day: u32,
     pub name: &'a str,
     pub input: &'a [u8],
     pub solve: Solver,
 }
 
+/// Checks that all `solutions` are for `year`, with valid days and no day
+/// registered twice. Evaluated in a constant by each year crate, so mistakes
+/// fail to compile:
+///
+/// ```ignore
+/// const _: () = common::check_solutions(2024, SOLUTIONS);
+/// ```
+pub const fn check_solutions(year: u32, solutions: &[Solution]) {
+    let mut days = RegisteredDays::new(year);
+    let mut i = 0;
+    while i < solutions.len() {
+        days = days.add(solutions[i].year, solutions[i].day);
+        i += 1;
+    }
+}
+
+/// Days registered so far for a year, for checking the solutions of a year
+/// crate in a constant. Shared by the `check_solutions` of both versions of
+/// the common crate.
+#[derive(Debug, Clone, Copy)]
+pub struct RegisteredDays {
+    year: u32,
+    /// Bit `day` is set for every registered day.
+    days: u32,
+}
+
+impl RegisteredDays {
+    pub const fn new(year: u32) -> Self {
+        RegisteredDays { year, days: 0 }
+    }
+
+    /// Registers `day` of `year`.
+    ///
+    /// # Panics
+    ///
+    /// If `year` is the wrong year, `day` is outside 1-25 or already
+    /// registered.
+    pub const fn add(self, year: u32, day: u32) -> Self {
+        assert!(year == self.year, "solution registered for the wrong year");
+        assert!(
+            day >= 1 && day <= 25,
+            "solution registered for a day outside 1-25"
+        );
+        assert!(
+            self.days & (1 << day) == 0,
+            "two solutions registered for the same day"
+        );
+        RegisteredDays {
+            year: self.year,
+            days: self.days | (1 << day),
+        }
+    }
+}
+
 pub fn from_option<T: Display>(value: Option<T>) -> String {
     match value {
         Some(x) => format!("{}", x),
@@ -15,6 +79,8 @@ pub fn from_option<T: Display>(value: Option<T>) -> String {
     }
 }
 
+// Answers drawn in block letters are compared by the letters they show, so
+// tests can give either the letters or the drawing.
 #[macro_export]
 macro_rules! example {
     (p1, $name:ident, $input:literal, $output:literal $(, $attr:meta),*) => {
@@ -23,7 +89,7 @@ macro_rules! example {
         fn $name() {
             println!("input: {}", $input);
             let (result, _) = solve(str::as_bytes($input));
-            assert_eq!(result, $output);
+            assert_eq!($crate::ocr::normalise(&result), $crate::ocr::normalise($output));
         }
     };
     (p2, $name:ident, $input:literal, $output:literal $(, $attr:meta),*) => {
@@ -32,7 +98,7 @@ macro_rules! example {
         fn $name() {
             println!("input: {}", $input);
             let (_, result) = solve(str::as_bytes($input));
-            assert_eq!(result, $output);
+            assert_eq!($crate::ocr::normalise(&result), $crate::ocr::normalise($output));
         }
     };
 }
@@ -44,7 +110,7 @@ macro_rules! solution {
         $(#[$attr])*
         fn $name() {
             let (result, _) = solve(SOLUTION.input);
-            assert_eq!(result, $output);
+            assert_eq!($crate::ocr::normalise(&result), $crate::ocr::normalise($output));
         }
     };
     (p2, $name:ident, $output:literal $(, $attr:meta),*) => {
@@ -52,7 +118,60 @@ macro_rules! solution {
         $(#[$attr])*
         fn $name() {
             let (_, result) = solve(SOLUTION.input);
-            assert_eq!(result, $output);
+            assert_eq!($crate::ocr::normalise(&result), $crate::ocr::normalise($output));
+        }
+    };
+}
+
+/// Example test with the input from a file, generated for the files in
+/// the `examples` directories of the days, see [`examples`].
+// `crate` is the solution crate the tests are generated for.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! example_file {
+    (p1, $day:ident, $name:ident, $input:literal, $output:literal) => {
+        #[test]
+        fn $name() {
+            let (result, _) = crate::$day::solve(str::as_bytes($input));
+            assert_eq!(
+                $crate::ocr::normalise(&result),
+                $crate::ocr::normalise($output)
+            );
+        }
+    };
+    (p2, $day:ident, $name:ident, $input:literal, $output:literal) => {
+        #[test]
+        fn $name() {
+            let (_, result) = crate::$day::solve(str::as_bytes($input));
+            assert_eq!(
+                $crate::ocr::normalise(&result),
+                $crate::ocr::normalise($output)
+            );
+        }
+    };
+}
+
+/// Fuzz test of `solve` with mutations of the embedded input, generated for
+/// the days with an input, see [`examples`] and [`fuzz`]. The literals are
+/// substrings of the panic messages documented to be raised for invalid
+/// input.
+// `crate` is the solution crate the tests are generated for.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! fuzz_file {
+    ($day:ident $(, $documented:literal)* $(,)?) => {
+        #[test]
+        #[ignore = "fuzzing is slow, run with --ignored"]
+        fn fuzz() {
+            $crate::fuzz::check(
+                &$crate::fuzz::test_name(module_path!(), "fuzz"),
+                crate::$day::SOLUTION.input,
+                |input| {
+                    (crate::$day::SOLUTION.solve)(input);
+                    Ok(())
+                },
+                &[$($documented),*],
+            );
         }
     };
 }
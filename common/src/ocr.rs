@@ -0,0 +1,210 @@
+//! Recognition of the block letters some puzzles draw as their answer.
+//!
+//! Puzzles like 2022 day 10 answer with a few capital letters drawn in a
+//! grid of `#` and `.`. Two fonts are used by Advent of Code, one 4 wide
+//! and 6 high and one 6 wide and 10 high. Letters are told apart by the
+//! empty columns between them, so the width of each letter does not matter.
+
+use std::borrow::Cow;
+
+type Glyph = (char, &'static [&'static str]);
+
+/// Letters 4 wide (except `Y`) and 6 high.
+const FONT_6: &[Glyph] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters 6 wide and 10 high.
+#[rustfmt::skip]
+const FONT_10: &[Glyph] = &[
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Lit cells of a grid, indexed by row and then column.
+type Cells = Vec<Vec<bool>>;
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Cells of the columns `from..to`, unlit past the end of a row.
+fn columns(rows: &Cells, from: usize, to: usize) -> Cells {
+    rows.iter()
+        .map(|row| (from..to).map(|x| row.get(x) == Some(&true)).collect())
+        .collect()
+}
+
+/// Cells of a glyph from the font, without empty columns at the sides.
+fn glyph_cells(glyph: &[&str]) -> Cells {
+    let rows: Cells = glyph
+        .iter()
+        .map(|row| row.chars().map(is_lit).collect())
+        .collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row[x]);
+    let from = (0..width).find(|&x| lit(x)).unwrap_or(0);
+    let to = (0..width).rfind(|&x| lit(x)).map_or(0, |x| x + 1);
+    columns(&rows, from, to)
+}
+
+/// Reads the letters drawn in `art`, with `#` or `█` for lit cells and `.`
+/// or space for unlit cells. Returns `None` if `art` contains anything else,
+/// is not the height of one of the fonts or has a letter that is not known.
+pub fn recognise(art: &str) -> Option<String> {
+    if !art
+        .chars()
+        .all(|c| is_lit(c) || matches!(c, '.' | ' ' | '\n' | '\r'))
+    {
+        return None;
+    }
+
+    let mut rows: Cells = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .collect();
+    // Empty rows above and below the letters carry no information.
+    while rows.last().is_some_and(|row| !row.contains(&true)) {
+        rows.pop();
+    }
+    let first = rows.iter().position(|row| row.contains(&true))?;
+    rows.drain(..first);
+
+    let font = match rows.len() {
+        6 => FONT_6,
+        10 => FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let from = x;
+        while x < width && lit(x) {
+            x += 1;
+        }
+        let cells = columns(&rows, from, x);
+        let (letter, _) = font.iter().find(|(_, glyph)| glyph_cells(glyph) == cells)?;
+        text.push(*letter);
+    }
+
+    Some(text)
+}
+
+/// Returns the letters drawn in `answer` if it is recognised as block
+/// letters, or `answer` unchanged otherwise.
+pub fn normalise(answer: &str) -> Cow<'_, str> {
+    match recognise(answer) {
+        Some(text) => Cow::Owned(text),
+        None => Cow::Borrowed(answer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws `text` in the given font with one empty column between letters.
+    fn draw(font: &[Glyph], text: &str) -> String {
+        let height = font[0].1.len();
+        let glyphs: Vec<&[&str]> = text
+            .chars()
+            .map(|c| font.iter().find(|(letter, _)| *letter == c).unwrap().1)
+            .collect();
+        (0..height)
+            .map(|y| {
+                glyphs
+                    .iter()
+                    .map(|glyph| glyph[y])
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn recognise_2022_day_10() {
+        let art = "\
+####.####..##..####.####.#....#..#.####.
+#....#....#..#....#.#....#....#..#.#....
+###..###..#......#..###..#....####.###..
+#....#....#.....#...#....#....#..#.#....
+#....#....#..#.#....#....#....#..#.#....
+#....####..##..####.####.####.#..#.####.";
+        assert_eq!(recognise(art).as_deref(), Some("FECZELHE"));
+    }
+
+    #[test]
+    fn recognise_whole_fonts() {
+        for font in [FONT_6, FONT_10] {
+            let alphabet: String = font.iter().map(|(letter, _)| letter).collect();
+            assert_eq!(recognise(&draw(font, &alphabet)), Some(alphabet));
+        }
+    }
+
+    #[test]
+    fn recognise_other_symbols() {
+        let art = draw(FONT_10, "HAX").replace('#', "█").replace('.', " ");
+        assert_eq!(
+            recognise(&format!("\n{art}\n      \n")).as_deref(),
+            Some("HAX")
+        );
+    }
+
+    #[test]
+    fn unrecognised() {
+        // Not the height of a font.
+        assert_eq!(recognise("#..#\n####"), None);
+        // Other characters.
+        assert_eq!(recognise("12540"), None);
+        assert_eq!(recognise(&draw(FONT_6, "AB").replace('.', "x")), None);
+        // Unknown letter.
+        let art = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(recognise(art), None);
+        assert_eq!(normalise(art), art);
+    }
+}
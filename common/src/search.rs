@@ -0,0 +1,469 @@
+//! Shortest path searches over graphs given by a neighbour function.
+//!
+//! All searches start from any number of nodes and stop once a node
+//! matching `goal` is reached, or when every reachable node has been visited
+//! if nothing matches. The resulting [`Paths`] keep every predecessor on a
+//! shortest path, so both a single path and all nodes on any shortest path
+//! can be recovered.
+//!
+//! ```
+//! use common::search;
+//!
+//! // Steps of +1 or *2 from 1 to 10.
+//! let paths = search::bfs([1], |&n| [n + 1, n * 2], |&n| n == 10);
+//! assert_eq!(paths.goal_cost(), Some(4));
+//! assert_eq!(paths.goal_path(), Some(vec![1, 2, 4, 5, 10]));
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cost of a path, with [`Default`] as no cost at all.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = Self> + Default> Cost for C {}
+
+/// Result of a search.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    visits: HashMap<N, Visit<N, C>>,
+    goal: Option<N>,
+}
+
+#[derive(Debug, Clone)]
+struct Visit<N, C> {
+    cost: C,
+    /// Neighbours this node is reached from on a shortest path.
+    predecessors: Vec<N>,
+    /// Whether `cost` is known to be the lowest. Nodes left in the queue when
+    /// the search stops are not settled.
+    settled: bool,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    fn new(starts: impl IntoIterator<Item = N>) -> Self {
+        let visits = starts
+            .into_iter()
+            .map(|start| {
+                let visit = Visit {
+                    cost: C::default(),
+                    predecessors: Vec::new(),
+                    settled: false,
+                };
+                (start, visit)
+            })
+            .collect();
+        Paths { visits, goal: None }
+    }
+
+    /// Marks `node` reached with `cost` as settled, unless it already is or
+    /// `cost` is outdated.
+    fn settle(&mut self, node: &N, cost: C) -> bool {
+        match self.visits.get_mut(node) {
+            Some(visit) if !visit.settled && visit.cost == cost => {
+                visit.settled = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Records reaching `to` from `from` with a total of `cost`. Returns
+    /// whether that is cheaper than any way found before. Ways as cheap as
+    /// the best are kept as predecessors, even if `to` is settled already,
+    /// as A* may settle a node before all its predecessors are expanded.
+    fn relax(&mut self, from: &N, to: N, cost: C) -> bool {
+        match self.visits.entry(to) {
+            Entry::Vacant(entry) => {
+                entry.insert(Visit {
+                    cost,
+                    predecessors: vec![from.clone()],
+                    settled: false,
+                });
+                true
+            }
+            Entry::Occupied(mut entry) => {
+                let visit = entry.get_mut();
+                match cost.cmp(&visit.cost) {
+                    Ordering::Less if visit.settled => false,
+                    Ordering::Less => {
+                        visit.cost = cost;
+                        visit.predecessors = vec![from.clone()];
+                        true
+                    }
+                    Ordering::Equal => {
+                        visit.predecessors.push(from.clone());
+                        false
+                    }
+                    Ordering::Greater => false,
+                }
+            }
+        }
+    }
+
+    /// The first node found matching the goal, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Cost of the shortest path to the goal.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// A shortest path to the goal, see [`Paths::path`].
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Cost of the shortest path to `node`, if the search got far enough to
+    /// know it.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.visits
+            .get(node)
+            .filter(|visit| visit.settled)
+            .map(|visit| visit.cost)
+    }
+
+    /// Nodes `node` is reached from on shortest paths to it, empty for the
+    /// starts.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.visits
+            .get(node)
+            .filter(|visit| visit.settled)
+            .map_or(&[], |visit| &visit.predecessors)
+    }
+
+    /// A shortest path to `node`, from a start up to and including `node`.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.cost(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All nodes on any shortest path to `node`, including `node` and the
+    /// starts. Empty if the cost of `node` is not known.
+    pub fn on_shortest_paths(&self, node: &N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut next = Vec::new();
+        if self.cost(node).is_some() {
+            next.push(node.clone());
+        }
+        while let Some(current) = next.pop() {
+            for previous in self.predecessors(&current) {
+                if !nodes.contains(previous) {
+                    next.push(previous.clone());
+                }
+            }
+            nodes.insert(current);
+        }
+        nodes
+    }
+
+    /// Every node with a known cost, in no particular order.
+    pub fn visited(&self) -> impl Iterator<Item = (&N, C)> {
+        self.visits
+            .iter()
+            .filter(|(_, visit)| visit.settled)
+            .map(|(node, visit)| (node, visit.cost))
+    }
+}
+
+/// Breadth-first search, where every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(starts);
+    let mut next: VecDeque<(usize, N)> = paths.visits.keys().map(|n| (0, n.clone())).collect();
+    let mut limit = usize::MAX;
+
+    while let Some((cost, current)) = next.pop_front() {
+        // Keep going while other shortest paths to the goal may be found.
+        if cost > limit {
+            break;
+        }
+        if !paths.settle(&current, cost) {
+            continue;
+        }
+        if paths.goal.is_none() && goal(&current) {
+            paths.goal = Some(current);
+            limit = cost;
+            continue;
+        }
+        for neighbour in neighbours(&current) {
+            if paths.relax(&current, neighbour.clone(), cost + 1) {
+                next.push_back((cost + 1, neighbour));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm, where `cost` gives the cost of a step between two
+/// neighbours. Every step must cost more than nothing, see [`astar`].
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    cost: impl FnMut(&N, &N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = N>,
+{
+    astar(starts, neighbours, cost, |_| C::default(), goal)
+}
+
+/// A* search, where `cost` gives the cost of a step between two neighbours
+/// and `heuristic` estimates the remaining cost from a node to the goal.
+///
+/// Every step must cost more than [`Default`]. With free steps two nodes can
+/// become each other's predecessors, and following them back never reaches
+/// a start. Debug builds panic on such a step.
+///
+/// The heuristic must be consistent: zero at the goal, and never more than
+/// the cost of a step plus the estimate from where the step leads. Otherwise
+/// a longer path, or only some of the shortest paths, may be found. The
+/// Manhattan distance is consistent on a grid where every step costs at
+/// least one.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut cost: impl FnMut(&N, &N) -> C,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(starts);
+    let mut next: BinaryHeap<Queued<N, C>> = paths
+        .visits
+        .keys()
+        .map(|node| Queued {
+            estimate: heuristic(node),
+            cost: C::default(),
+            node: node.clone(),
+        })
+        .collect();
+    let mut limit = None;
+
+    while let Some(Queued {
+        estimate,
+        cost: current_cost,
+        node: current,
+    }) = next.pop()
+    {
+        // Keep going while other shortest paths to the goal may be found.
+        if limit.is_some_and(|limit| estimate > limit) {
+            break;
+        }
+        if !paths.settle(&current, current_cost) {
+            continue;
+        }
+        if paths.goal.is_none() && goal(&current) {
+            paths.goal = Some(current);
+            limit = Some(estimate);
+            continue;
+        }
+        for neighbour in neighbours(&current) {
+            let step = cost(&current, &neighbour);
+            debug_assert!(step > C::default(), "steps should cost more than nothing");
+            let neighbour_cost = current_cost + step;
+            if paths.relax(&current, neighbour.clone(), neighbour_cost) {
+                next.push(Queued {
+                    estimate: neighbour_cost + heuristic(&neighbour),
+                    cost: neighbour_cost,
+                    node: neighbour,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// Node waiting in the queue, ordered so the lowest estimate comes first.
+struct Queued<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal estimates, prefer the node furthest along.
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Pos = (i32, i32);
+
+    /// Open cells of a small maze, with `S` and `E` as start and end.
+    fn maze(map: &str) -> (HashSet<Pos>, Pos, Pos) {
+        let mut open = HashSet::new();
+        let (mut start, mut end) = ((0, 0), (0, 0));
+        for (y, line) in (0..).zip(map.lines()) {
+            for (x, c) in (0..).zip(line.chars()) {
+                match c {
+                    'S' => start = (x, y),
+                    'E' => end = (x, y),
+                    '.' => {}
+                    _ => continue,
+                }
+                open.insert((x, y));
+            }
+        }
+        (open, start, end)
+    }
+
+    fn steps(open: &HashSet<(i32, i32)>, (x, y): (i32, i32)) -> Vec<(i32, i32)> {
+        [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
+            .into_iter()
+            .filter(|pos| open.contains(pos))
+            .collect()
+    }
+
+    const MAZE: &str = "\
+#######
+#S....#
+#.##.##
+#.....#
+###.#E#
+#.....#
+#######";
+
+    #[test]
+    fn breadth_first() {
+        let (open, start, end) = maze(MAZE);
+        let paths = bfs([start], |&pos| steps(&open, pos), |&pos| pos == end);
+
+        assert_eq!(paths.goal(), Some(&end));
+        assert_eq!(paths.goal_cost(), Some(7));
+        let path = paths.goal_path().unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), (start, end));
+        // Both ways around the wall are as short.
+        assert_eq!(paths.on_shortest_paths(&end).len(), 12);
+        assert_eq!(paths.cost(&(1, 5)), None);
+    }
+
+    #[test]
+    fn unreachable() {
+        let (open, start, _) = maze(MAZE);
+        let paths = bfs([start], |&pos| steps(&open, pos), |_| false);
+
+        assert_eq!(paths.goal(), None);
+        assert_eq!(paths.goal_path(), None);
+        assert_eq!(paths.visited().count(), open.len());
+        assert_eq!(paths.cost(&(1, 5)), Some(8));
+        assert!(paths.on_shortest_paths(&(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn weighted() {
+        let (open, start, end) = maze(MAZE);
+        // Steps further down cost more, so the path stays at the top for as
+        // long as it can.
+        let cost = |_: &(i32, i32), &(_, y): &(i32, i32)| y + 1;
+        let paths = dijkstra([start], |&pos| steps(&open, pos), cost, |&pos| pos == end);
+        let path = paths.goal_path().unwrap();
+
+        assert_eq!(paths.goal_cost(), Some(22));
+        assert_eq!(path[1..4], [(2, 1), (3, 1), (4, 1)]);
+        assert_eq!(paths.on_shortest_paths(&end).len(), path.len());
+
+        let manhattan = |&(x, y): &(i32, i32)| (end.0 - x).abs() + (end.1 - y).abs();
+        let guided = astar(
+            [start],
+            |&pos| steps(&open, pos),
+            cost,
+            manhattan,
+            |&pos| pos == end,
+        );
+        assert_eq!(guided.goal_cost(), Some(22));
+        assert!(guided.visited().count() <= paths.visited().count());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "steps should cost more than nothing")]
+    fn free_steps() {
+        let (open, start, end) = maze(MAZE);
+        // Walking left or right is free, which would let neighbours on a row
+        // become each other's predecessors.
+        let cost = |&(_, from): &(i32, i32), &(_, to): &(i32, i32)| i32::from(from != to);
+        dijkstra([start], |&pos| steps(&open, pos), cost, |&pos| pos == end);
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        // Every path right and down across an open grid is as short.
+        let open: HashSet<Pos> = (0..3).flat_map(|x| (0..3).map(move |y| (x, y))).collect();
+        let (start, end) = ((0, 0), (2, 2));
+        let cost = |_: &Pos, _: &Pos| 1;
+        let manhattan = |&(x, y): &Pos| (end.0 - x).abs() + (end.1 - y).abs();
+
+        let paths = dijkstra([start], |&pos| steps(&open, pos), cost, |&pos| pos == end);
+        let guided = astar(
+            [start],
+            |&pos| steps(&open, pos),
+            cost,
+            manhattan,
+            |&pos| pos == end,
+        );
+        let unweighted = bfs([start], |&pos| steps(&open, pos), |&pos| pos == end);
+
+        assert_eq!(paths.on_shortest_paths(&end), open);
+        assert_eq!(guided.on_shortest_paths(&end), open);
+        assert_eq!(unweighted.on_shortest_paths(&end), open);
+        assert_eq!(guided.predecessors(&end).len(), 2);
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let (open, start, end) = maze(MAZE);
+        let paths = bfs([start, (1, 5)], |&pos| steps(&open, pos), |&pos| pos == end);
+
+        assert_eq!(paths.goal_cost(), Some(5));
+        assert_eq!(paths.goal_path().unwrap()[0], (1, 5));
+    }
+}
@@ -1,16 +1,101 @@
+/// Fuzz testing support, shared with the solutions using `common`.
+pub mod fuzz {
+    pub use common::fuzz::{Target, check, test_name};
+}
+
+pub mod progress;
+pub mod visual;
+
+pub use common::{cycle, geometry, grid, search};
+
 pub mod prelude {
-    pub use super::{Output, PartSolver, Solver};
+    pub use super::geometry::{Direction, Point};
+    pub use super::grid::Grid;
+    pub use super::{Memo, Output, ParseError, ParseResult, PartSolver, Solver, progress, visual};
 }
 
 use std::fmt::Display;
+use std::sync::OnceLock;
+
+use grid::Grid;
+use miette::{Diagnostic, LabeledSpan, SourceSpan};
+use num::{BigInt, BigRational, BigUint};
 
-#[derive(Debug, PartialEq, Eq)]
+/// Answer to one part of a puzzle.
+///
+/// Numbers compare equal by value regardless of the variant holding them, so
+/// `UInt(4)`, `BigInt(4)` and `Rational(8/2)` are all the same answer. Grids
+/// compare line by line with trailing whitespace ignored, also against a
+/// `String` holding the same lines.
+#[derive(Debug)]
 pub enum Output {
     UInt(u128),
     Int(i128),
+    /// Integer too large for `UInt` or `Int`.
+    BigInt(BigInt),
+    /// Exact fraction, shown as `numerator/denominator`.
+    Rational(BigRational),
     String(Box<str>),
+    /// Multi-line answer, like letters drawn in ASCII art.
+    Grid(Box<[Box<str>]>),
     NoSolution,
     Todo,
+    /// The part failed at runtime, like an illegal instruction or a process
+    /// that never converges. Never equal to any output, not even itself, so
+    /// an error can not pass for an answer.
+    Error(miette::Report),
+}
+
+impl Output {
+    pub fn error(err: impl Into<miette::Report>) -> Self {
+        Self::Error(err.into())
+    }
+
+    /// Creates a multi-line answer from its lines.
+    pub fn grid<L: AsRef<str>>(lines: impl IntoIterator<Item = L>) -> Self {
+        Self::Grid(lines.into_iter().map(|line| line.as_ref().into()).collect())
+    }
+
+    /// The value of a numeric output.
+    fn number(&self) -> Option<BigRational> {
+        let integer = |x: BigInt| Some(BigRational::from_integer(x));
+        match self {
+            Output::UInt(x) => integer((*x).into()),
+            Output::Int(x) => integer((*x).into()),
+            Output::BigInt(x) => integer(x.clone()),
+            Output::Rational(x) => Some(x.clone()),
+            _ => None,
+        }
+    }
+
+    /// Lines of a textual output, without trailing whitespace and trailing
+    /// empty lines.
+    fn lines(&self) -> Option<Vec<&str>> {
+        let mut lines: Vec<&str> = match self {
+            Output::String(x) => x.lines().map(str::trim_end).collect(),
+            Output::Grid(x) => x.iter().map(|line| line.trim_end()).collect(),
+            _ => return None,
+        };
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        Some(lines)
+    }
+}
+
+impl PartialEq for Output {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Output::Error(_), _) | (_, Output::Error(_)) => false,
+            (Output::String(a), Output::String(b)) => a == b,
+            (Output::Grid(_), _) | (_, Output::Grid(_)) => {
+                self.lines().is_some() && self.lines() == other.lines()
+            }
+            (Output::NoSolution, Output::NoSolution) => true,
+            (Output::Todo, Output::Todo) => true,
+            _ => self.number().is_some() && self.number() == other.number(),
+        }
+    }
 }
 
 impl From<u128> for Output {
@@ -73,6 +158,34 @@ impl From<i8> for Output {
     }
 }
 
+impl From<BigInt> for Output {
+    fn from(value: BigInt) -> Self {
+        Self::BigInt(value)
+    }
+}
+
+impl From<BigUint> for Output {
+    fn from(value: BigUint) -> Self {
+        Self::BigInt(value.into())
+    }
+}
+
+impl From<BigRational> for Output {
+    fn from(value: BigRational) -> Self {
+        Self::Rational(value)
+    }
+}
+
+impl<T: Display> From<&Grid<T>> for Output {
+    fn from(value: &Grid<T>) -> Self {
+        Output::grid(
+            value
+                .rows()
+                .map(|row| row.iter().map(ToString::to_string).collect::<String>()),
+        )
+    }
+}
+
 impl From<Box<str>> for Output {
     fn from(value: Box<str>) -> Self {
         Self::String(value)
@@ -91,29 +204,286 @@ impl From<String> for Output {
     }
 }
 
+impl<T, E> From<Result<T, E>> for Output
+where
+    T: Into<Output>,
+    E: Into<miette::Report>,
+{
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(value) => value.into(),
+            Err(err) => Self::error(err),
+        }
+    }
+}
+
 impl Display for Output {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Output::UInt(x) => write!(f, "{}", x),
             Output::Int(x) => write!(f, "{}", x),
+            Output::BigInt(x) => write!(f, "{}", x),
+            Output::Rational(x) => write!(f, "{}", x),
             Output::String(x) => write!(f, "{}", x),
+            Output::Grid(x) => write!(f, "{}", x.join("\n")),
             Output::NoSolution => write!(f, "No solution"),
             Output::Todo => write!(f, "!todo"),
+            Output::Error(err) => write!(f, "error: {}", err),
         }
     }
 }
 
 pub trait Solver {
+    /// Year of the puzzle, which the solution is registered under.
+    const YEAR: u32;
+    /// Day of the puzzle, which the solution is registered under.
+    const DAY: u32;
+
     fn title() -> &'static str;
     fn input() -> &'static [u8];
-    fn initial(input: &[u8]) -> Box<dyn PartSolver>;
+    fn initial(input: &[u8]) -> ParseResult;
 }
 
+/// Result of parsing the input, the state used to solve each part.
+pub type ParseResult = Result<Box<dyn PartSolver>, ParseError>;
+
 pub trait PartSolver {
     fn part1(&self) -> Output;
     fn part2(&self) -> Output;
 }
 
+/// Lazily computed value shared between the parts of a solution.
+///
+/// [`PartSolver`] only gets `&self`, so an intermediate result that both parts
+/// need, like a simulated grid or a table of shortest paths, can be kept in a
+/// `Memo` on the state. Whichever part runs first computes it and the other
+/// part reuses it. When timing the parts, the time to compute it is counted
+/// for the part that ran first.
+///
+/// ```
+/// # use common_v2::Memo;
+/// struct State {
+///     numbers: Vec<u64>,
+///     sorted: Memo<Vec<u64>>,
+/// }
+///
+/// impl State {
+///     fn sorted(&self) -> &[u64] {
+///         self.sorted.get_or_compute(|| {
+///             let mut sorted = self.numbers.clone();
+///             sorted.sort();
+///             sorted
+///         })
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Memo<T>(OnceLock<T>);
+
+impl<T> Memo<T> {
+    pub const fn new() -> Self {
+        Memo(OnceLock::new())
+    }
+
+    /// Returns the value, computing it with `compute` if this is the first
+    /// call.
+    pub fn get_or_compute(&self, compute: impl FnOnce() -> T) -> &T {
+        self.0.get_or_init(compute)
+    }
+
+    /// Returns the value if it has been computed.
+    pub fn get(&self) -> Option<&T> {
+        self.0.get()
+    }
+}
+
+/// Error for input that could not be parsed, pointing at the offending part
+/// of the input.
+///
+/// The error does not hold on to the input itself. Use
+/// [`ParseError::with_input`] to get a [`miette::Report`] that renders the
+/// offending line with the span highlighted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    span: SourceSpan,
+    label: Option<String>,
+    help: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(span: impl Into<SourceSpan>, message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            span: span.into(),
+            label: None,
+            help: None,
+        }
+    }
+
+    /// Creates an error pointing at `part`, which must be a subslice of
+    /// `input`. This is handy for parsers working on slices of the input.
+    pub fn at(input: &[u8], part: &[u8], message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + part.len() <= input.len())
+            .expect("part should be a subslice of input");
+        Self::new((offset, part.len()), message)
+    }
+
+    /// Sets the text shown next to the highlighted span.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets a hint on how to fix the input.
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Location of the offending part of the input.
+    pub fn span(&self) -> SourceSpan {
+        self.span
+    }
+
+    /// Attaches the input the error points into, naming it `name` in the
+    /// rendered snippet.
+    pub fn with_input(self, name: &str, input: &[u8]) -> miette::Report {
+        let source = String::from_utf8_lossy(input).into_owned();
+        miette::Report::new(self).with_source_code(miette::NamedSource::new(name, source))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Diagnostic for ParseError {
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = LabeledSpan::new_with_span(self.label.clone(), self.span);
+        Some(Box::new(std::iter::once(label)))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|help| Box::new(help) as Box<dyn Display>)
+    }
+}
+
+/// Type-erased handle to a [`Solver`]. `Solver` only has associated functions
+/// and can therefore not be made into a trait object, so this holds pointers
+/// to them instead. This lets solvers for different days be stored together.
+#[derive(Clone, Copy)]
+pub struct DynSolver {
+    pub year: u32,
+    pub day: u32,
+    pub title: fn() -> &'static str,
+    pub input: fn() -> &'static [u8],
+    pub initial: fn(&[u8]) -> ParseResult,
+}
+
+impl DynSolver {
+    pub const fn new<S: Solver>() -> Self {
+        DynSolver {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::title,
+            input: S::input,
+            initial: S::initial,
+        }
+    }
+}
+
+/// Checks that all `solutions` are for `year`, with valid days and no day
+/// registered twice. Evaluated in a constant by each year crate, so mistakes
+/// fail to compile:
+///
+/// ```ignore
+/// const _: () = common_v2::check_solutions(2025, SOLUTIONS);
+/// ```
+pub const fn check_solutions(year: u32, solutions: &[DynSolver]) {
+    let mut days = common::RegisteredDays::new(year);
+    let mut i = 0;
+    while i < solutions.len() {
+        days = days.add(solutions[i].year, solutions[i].day);
+        i += 1;
+    }
+}
+
+/// Solver function used by the first version of the common crate, solving
+/// both parts in one go.
+pub type LegacySolver = fn(&[u8]) -> (String, String);
+
+/// Adapts a [`LegacySolver`] into a [`PartSolver`]. Legacy solvers can not
+/// solve the parts separately, so both are solved up front when the adapter
+/// is created.
+pub struct LegacyAdapter {
+    part1: Box<str>,
+    part2: Box<str>,
+}
+
+impl LegacyAdapter {
+    pub fn new(solve: LegacySolver, input: &[u8]) -> Self {
+        let (part1, part2) = solve(input);
+        LegacyAdapter {
+            part1: part1.into(),
+            part2: part2.into(),
+        }
+    }
+}
+
+impl PartSolver for LegacyAdapter {
+    fn part1(&self) -> Output {
+        Output::String(self.part1.clone())
+    }
+
+    fn part2(&self) -> Output {
+        Output::String(self.part2.clone())
+    }
+}
+
+/// Parses the input with the given solver, panicking with the rendered
+/// [`ParseError`] if it is invalid. Used by the test macros.
+pub fn parse<S: Solver>(input: &[u8]) -> Box<dyn PartSolver> {
+    S::initial(input).unwrap_or_else(|err| panic!("{:?}", err.with_input("input", input)))
+}
+
+/// Asserts that a part gave the expected output, showing the full diagnostic
+/// if it failed instead. Used by the test macros.
+#[track_caller]
+pub fn assert_output(result: Output, expected: Output) {
+    if let Output::Error(err) = &result {
+        panic!("expected {expected}, but the part failed: {err:?}");
+    }
+    assert_eq!(result, expected);
+}
+
+/// Asserts that `result` is shown as `expected`, with answers drawn in block
+/// letters compared by the letters. Used by the tests generated from example
+/// files, which only know the answers as text.
+#[track_caller]
+pub fn assert_answer(result: Output, expected: &str) {
+    if let Output::Error(err) = &result {
+        panic!("expected {expected}, but the part failed: {err:?}");
+    }
+    let result = result.to_string();
+    assert_eq!(
+        common::ocr::normalise(&result),
+        common::ocr::normalise(expected)
+    );
+}
+
 #[macro_export]
 macro_rules! example {
     (p1, $name:ident, $input:literal, $output:literal $(, $attr:meta),*) => {
@@ -121,8 +491,8 @@ macro_rules! example {
         $(#[$attr])*
         fn $name() {
             println!("input: {}", $input);
-            let result = Solution::initial(str::as_bytes($input)).part1();
-            assert_eq!(result, Output::from($output));
+            let result = $crate::parse::<Solution>(str::as_bytes($input)).part1();
+            $crate::assert_output(result, Output::from($output));
         }
     };
     (p2, $name:ident, $input:literal, $output:literal $(, $attr:meta),*) => {
@@ -130,8 +500,8 @@ macro_rules! example {
         $(#[$attr])*
         fn $name() {
             println!("input: {}", $input);
-            let result = Solution::initial(str::as_bytes($input)).part2();
-            assert_eq!(result, Output::from($output));
+            let result = $crate::parse::<Solution>(str::as_bytes($input)).part2();
+            $crate::assert_output(result, Output::from($output));
         }
     };
 }
@@ -142,16 +512,156 @@ macro_rules! solution {
         #[test]
         $(#[$attr])*
         fn $name() {
-            let result = Solution::initial(Solution::input()).part1();
-            assert_eq!(result, Output::from($output));
+            let result = $crate::parse::<Solution>(Solution::input()).part1();
+            $crate::assert_output(result, Output::from($output));
         }
     };
     (p2, $name:ident, $output:literal $(, $attr:meta),*) => {
         #[test]
         $(#[$attr])*
         fn $name() {
-            let result = Solution::initial(Solution::input()).part2();
-            assert_eq!(result, Output::from($output));
+            let result = $crate::parse::<Solution>(Solution::input()).part2();
+            $crate::assert_output(result, Output::from($output));
+        }
+    };
+}
+
+/// Example test with the input from a file, generated for the files in
+/// the `examples` directories of the days, see [`common::examples`]. The
+/// expected answer is compared with the output as text.
+// `crate` is the solution crate the tests are generated for.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! example_file {
+    (p1, $day:ident, $name:ident, $input:literal, $output:literal) => {
+        #[test]
+        fn $name() {
+            let result = $crate::parse::<crate::$day::Solution>(str::as_bytes($input)).part1();
+            $crate::assert_answer(result, $output);
+        }
+    };
+    (p2, $day:ident, $name:ident, $input:literal, $output:literal) => {
+        #[test]
+        fn $name() {
+            let result = $crate::parse::<crate::$day::Solution>(str::as_bytes($input)).part2();
+            $crate::assert_answer(result, $output);
+        }
+    };
+}
+
+/// Fuzz test of `Solver::initial` and both parts with mutations of the
+/// embedded input, generated for the days with an input, see
+/// [`common::examples`] and [`common::fuzz`]. Inputs rejected with a
+/// [`ParseError`] pass. The literals are substrings of the panic messages
+/// documented to be raised for invalid input.
+// `crate` is the solution crate the tests are generated for.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! fuzz_file {
+    ($day:ident $(, $documented:literal)* $(,)?) => {
+        #[test]
+        #[ignore = "fuzzing is slow, run with --ignored"]
+        fn fuzz() {
+            use $crate::Solver;
+
+            $crate::fuzz::check(
+                &$crate::fuzz::test_name(module_path!(), "fuzz"),
+                crate::$day::Solution::input(),
+                |input| {
+                    let state =
+                        crate::$day::Solution::initial(input).map_err(|err| err.to_string())?;
+                    state.part1();
+                    state.part2();
+                    Ok(())
+                },
+                &[$($documented),*],
+            );
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_at_subslice() {
+        let input = b"L68\nX30\n";
+        let err = ParseError::at(input, &input[4..5], "expected direction to be L or R");
+        assert_eq!(err.span(), SourceSpan::from((4, 1)));
+
+        let rendered = format!("{:?}", err.with_input("input", input));
+        assert!(rendered.contains("X30"), "{rendered}");
+    }
+
+    #[test]
+    fn memo_computes_once() {
+        let memo = Memo::new();
+        assert_eq!(memo.get(), None);
+
+        let mut computed = 0;
+        for _ in 0..3 {
+            let value = memo.get_or_compute(|| {
+                computed += 1;
+                42
+            });
+            assert_eq!(*value, 42);
+        }
+        assert_eq!(computed, 1);
+        assert_eq!(memo.get(), Some(&42));
+    }
+
+    #[test]
+    fn numbers_equal_by_value() {
+        let big: BigInt = "340282366920938463463374607431768211456".parse().unwrap();
+        assert_eq!(Output::from(big.clone()), Output::from(big.clone()));
+        assert_ne!(Output::from(big), Output::UInt(u128::MAX));
+
+        assert_eq!(Output::UInt(4), Output::Int(4));
+        assert_eq!(Output::Int(-4), Output::from(BigInt::from(-4)));
+        assert_ne!(Output::UInt(4), Output::from("4"));
+
+        let half = BigRational::new(2.into(), 4.into());
+        assert_eq!(Output::from(half.clone()).to_string(), "1/2");
+        assert_ne!(Output::from(half), Output::Int(0));
+        let two = BigRational::new(8.into(), 4.into());
+        assert_eq!(Output::from(two.clone()), Output::Int(2));
+        assert_eq!(Output::from(two).to_string(), "2");
+    }
+
+    #[test]
+    fn from_grid() {
+        let grid = Grid::parse("#.\n.#", |c| Some(c == '#')).unwrap();
+        let output = Output::from(&grid.map(|&lit| if lit { '#' } else { '.' }));
+        assert_eq!(output, Output::from("#.\n.#"));
+    }
+
+    #[test]
+    fn grids_equal_by_line() {
+        let grid = Output::grid(["#..#  ", ".##."]);
+        assert_eq!(grid.to_string(), "#..#  \n.##.");
+        assert_eq!(grid, Output::grid(["#..#", ".##.", ""]));
+        assert_eq!(grid, Output::from("#..#\n.##.\n"));
+        assert_ne!(grid, Output::from("#..#\n.#.."));
+        assert_ne!(Output::grid(["1"]), Output::UInt(1));
+    }
+
+    #[test]
+    fn error_is_never_an_answer() {
+        let error = || Output::error(miette::miette!("illegal opcode 9"));
+        assert_ne!(error(), error());
+        assert_ne!(error(), Output::from("error: illegal opcode 9"));
+        assert_eq!(error().to_string(), "error: illegal opcode 9");
+
+        let ok: Result<u32, miette::Report> = Ok(4);
+        assert_eq!(Output::from(ok), Output::UInt(4));
+        let err: Result<u32, miette::Report> = Err(miette::miette!("no cycle"));
+        assert!(matches!(Output::from(err), Output::Error(_)));
+    }
+
+    #[test]
+    #[should_panic(expected = "part should be a subslice of input")]
+    fn parse_error_outside_input() {
+        ParseError::at(b"L68", b"X30", "not in input");
+    }
+}
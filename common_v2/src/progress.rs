@@ -0,0 +1,163 @@
+//! Progress reporting for long-running solutions.
+//!
+//! Solutions can call [`count`] or [`status`] as often as they like while
+//! working. Nothing happens unless the runner has installed a reporter with
+//! [`set_reporter`], and updates are passed on at most every
+//! [`INTERVAL`], so reporting from a hot loop is cheap.
+//!
+//! ```ignore
+//! for (i, blueprint) in blueprints.iter().enumerate() {
+//!     progress::count(i, blueprints.len());
+//!     // ...
+//! }
+//! ```
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+
+/// Shortest time between two updates passed on to the reporter.
+pub const INTERVAL: Duration = Duration::from_millis(100);
+
+/// Progress of the running solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Update {
+    /// `done` of `total` steps are done.
+    Count { done: u64, total: u64 },
+    /// Free-form description of what the solution is doing.
+    Status(String),
+}
+
+impl Display for Update {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Update::Count { done, total } if *total > 0 => {
+                let percent = *done as f64 / *total as f64 * 100.0;
+                write!(f, "{done} of {total} ({percent:.0}%)")
+            }
+            Update::Count { done, total } => write!(f, "{done} of {total}"),
+            Update::Status(status) => write!(f, "{status}"),
+        }
+    }
+}
+
+pub type Reporter = Arc<dyn Fn(&Update) + Send + Sync>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static REPORTER: RwLock<Option<Reporter>> = RwLock::new(None);
+/// Time of the last update passed on, in nanoseconds since [`epoch`].
+static LAST: AtomicU64 = AtomicU64::new(0);
+
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+/// Sets the function receiving the updates of all solutions, or stops
+/// reporting if `None`.
+pub fn set_reporter(reporter: Option<Reporter>) {
+    let enabled = reporter.is_some();
+    *REPORTER.write().unwrap_or_else(|err| err.into_inner()) = reporter;
+    LAST.store(0, Ordering::Relaxed);
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether an update should be passed on now. Only one of the threads
+/// reporting at the same time gets to pass its update on.
+fn due() -> bool {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return false;
+    }
+    let now = epoch().elapsed().as_nanos() as u64;
+    let last = LAST.load(Ordering::Relaxed);
+    if last != 0 && now.saturating_sub(last) < INTERVAL.as_nanos() as u64 {
+        return false;
+    }
+    LAST.compare_exchange(last, now.max(1), Ordering::Relaxed, Ordering::Relaxed)
+        .is_ok()
+}
+
+fn send(update: Update) {
+    let reporter = REPORTER.read().unwrap_or_else(|err| err.into_inner());
+    if let Some(reporter) = reporter.as_ref() {
+        reporter(&update);
+    }
+}
+
+/// Reports that `done` of `total` steps are done. The last step is always
+/// passed on, so the final count is not lost to the throttling.
+pub fn count(done: impl TryInto<u64>, total: impl TryInto<u64>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let done = done.try_into().unwrap_or(u64::MAX);
+    let total = total.try_into().unwrap_or(u64::MAX);
+    if done >= total || due() {
+        send(Update::Count { done, total });
+    }
+}
+
+/// Reports what the solution is doing. The message is only formatted if
+/// the update is passed on.
+pub fn status(message: impl Display) {
+    if due() {
+        send(Update::Status(message.to_string()));
+    }
+}
+
+/// Like [`status`], but always passed on, for updates that must not be
+/// dropped, like the last one of a phase.
+pub fn status_now(message: impl Display) {
+    if ENABLED.load(Ordering::Relaxed) {
+        send(Update::Status(message.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn updates() {
+        assert_eq!(
+            Update::Count { done: 3, total: 12 }.to_string(),
+            "3 of 12 (25%)"
+        );
+        assert_eq!(Update::Count { done: 0, total: 0 }.to_string(), "0 of 0");
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let sink = updates.clone();
+        set_reporter(Some(Arc::new(move |update: &Update| {
+            sink.lock().unwrap().push(update.clone())
+        })));
+
+        // Only the first of several quick updates is passed on.
+        count(1, 10);
+        count(2usize, 10usize);
+        status("skipped");
+        count(10, 10);
+        status_now("done");
+        std::thread::sleep(INTERVAL);
+        status(format_args!("robot {}", 4));
+
+        set_reporter(None);
+        count(3, 10);
+        status_now("not reported");
+
+        assert_eq!(
+            *updates.lock().unwrap(),
+            [
+                Update::Count { done: 1, total: 10 },
+                Update::Count {
+                    done: 10,
+                    total: 10
+                },
+                Update::Status("done".to_owned()),
+                Update::Status("robot 4".to_owned())
+            ]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Frame-by-frame visualisation of solutions.
+//!
+//! Solutions can draw their state with [`frame`] as they work, which the
+//! runner plays back as an animation when run with `--visualise`. Frames are
+//! plain text, with ANSI escape codes allowed for colour. Nothing is
+//! formatted unless a recorder has been installed with [`set_recorder`], so
+//! drawing costs no more than a check of a flag otherwise.
+//!
+//! ```ignore
+//! for _ in 0..cycles {
+//!     platform.spin();
+//!     visual::frame(&platform);
+//! }
+//! ```
+//!
+//! Every frame is kept until the solution is done, so a solution running for
+//! many steps should only draw some of them.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+pub type Recorder = Arc<dyn Fn(String) + Send + Sync>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDER: RwLock<Option<Recorder>> = RwLock::new(None);
+
+/// Sets the function receiving the frames of all solutions, or stops
+/// recording if `None`.
+pub fn set_recorder(recorder: Option<Recorder>) {
+    let enabled = recorder.is_some();
+    *RECORDER.write().unwrap_or_else(|err| err.into_inner()) = recorder;
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether frames are being recorded, for solutions that need to do extra
+/// work to draw one.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records a frame. The frame is only formatted if it is recorded.
+pub fn frame(frame: impl Display) {
+    if !enabled() {
+        return;
+    }
+    let recorder = RECORDER.read().unwrap_or_else(|err| err.into_inner());
+    if let Some(recorder) = recorder.as_ref() {
+        recorder(frame.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn frames() {
+        struct Panics;
+        impl Display for Panics {
+            fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                panic!("formatted without a recorder");
+            }
+        }
+        frame(Panics);
+
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let sink = frames.clone();
+        set_recorder(Some(Arc::new(move |frame| {
+            sink.lock().unwrap().push(frame)
+        })));
+        assert!(enabled());
+        frame("#.\n.#");
+        frame(format_args!("step {}", 2));
+        set_recorder(None);
+        frame(Panics);
+
+        assert_eq!(*frames.lock().unwrap(), ["#.\n.#", "step 2"]);
+    }
+}
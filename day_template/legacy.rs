@@ -0,0 +1,29 @@
+pub const SOLUTION: common::Solution = common::Solution {
+    year: 0,
+    day: 0,
+    name: "Day X: Template",
+    input: std::include_bytes!("input"),
+    solve: self::solve,
+};
+
+pub fn solve(_input: &[u8]) -> (String, String) {
+    (0.to_string(), 0.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    // Examples go in `examples/<name>.txt`, with the answers in
+    // `examples/<name>.answers` as `part1: <answer>` and `part2: <answer>`.
+
+    // Part 1
+    // common::solution!(p1, p1_solution, "100");
+
+    // Part 2
+    // common::solution!(p2, p2_solution, "100");
+
+    // Once the input is filled in it is fuzzed, accepting the panic messages
+    // for invalid input listed in `panics.txt`.
+}
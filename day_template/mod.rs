@@ -1,31 +1,51 @@
-pub const SOLUTION: common::Solution = common::Solution {
-    name: "Day X: Template",
-    input: std::include_bytes!("input"),
-    solve: self::solve,
-};
-
-pub fn solve(input: &[u8]) -> (String, String) {
-    (0.to_string(), 0.to_string())
+use common_v2::prelude::*;
+
+pub struct Solution;
+
+pub struct State;
+
+impl Solver for Solution {
+    const YEAR: u32 = 0;
+    const DAY: u32 = 0;
+
+    fn title() -> &'static str {
+        "Day X: Template"
+    }
+
+    fn input() -> &'static [u8] {
+        static INPUT: &[u8] = std::include_bytes!("input");
+        INPUT
+    }
+
+    fn initial(_input: &[u8]) -> ParseResult {
+        Ok(Box::new(State))
+    }
+}
+
+impl PartSolver for State {
+    fn part1(&self) -> Output {
+        Output::Todo
+    }
+
+    fn part2(&self) -> Output {
+        Output::Todo
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    #[allow(unused_imports)]
     use super::*;
-    use common::{example, solution};
+
+    // Examples go in `examples/<name>.txt`, with the answers in
+    // `examples/<name>.answers` as `part1: <answer>` and `part2: <answer>`.
 
     // Part 1
-    // example!(p1, p1_example_1, "", "0");
-    // example!(p1, p1_example_2, "", "0");
-    // example!(p1, p1_example_3, "", "0");
-    // example!(p1, p1_example_4, "", "0");
-    // example!(p1, p1_example_5, "", "0");
-    // solution!(p1, p1_solution, "100");
+    // common_v2::solution!(p1, p1_solution, 100);
 
     // Part 2
-    // example!(p2, p2_example_1, "", "0");
-    // example!(p2, p2_example_2, "", "0");
-    // example!(p2, p2_example_3, "", "0");
-    // example!(p2, p2_example_4, "", "0");
-    // example!(p2, p2_example_5, "", "0");
-    // solution!(p2, p2_solution, "100");
+    // common_v2::solution!(p2, p2_solution, 100);
+
+    // Once the input is filled in it is fuzzed, accepting the panic messages
+    // for invalid input listed in `panics.txt`.
 }
@@ -0,0 +1,160 @@
+//! Wall-clock timing of solutions.
+//!
+//! A single run of a solution produces a [`Sample`]. When benchmarking, the
+//! same solution is run several times and the samples are summarised into
+//! [`Timings`] holding the min, median and max of every phase.
+
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+/// Time spent in each phase of a single run of a solution. Solutions that
+/// compute everything in one go, like [`common::Solution`], only report the
+/// total and leave the individual phases empty. Every year before 2025 is
+/// written that way, which the text report points out below the timings.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sample {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+    pub total: Duration,
+}
+
+/// Summary of a set of durations. Serialised as whole nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "as_nanos")]
+    pub max: Duration,
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Stats {
+    /// Computes the min, median and max of the given durations. Returns `None`
+    /// if there are no durations. For an even number of durations the median
+    /// is the mean of the two middle values.
+    pub fn from_durations(durations: impl IntoIterator<Item = Duration>) -> Option<Self> {
+        let mut durations: Vec<Duration> = durations.into_iter().collect();
+        durations.sort_unstable();
+
+        let n = durations.len();
+        let median = match n {
+            0 => return None,
+            n if n % 2 == 1 => durations[n / 2],
+            n => (durations[n / 2 - 1] + durations[n / 2]) / 2,
+        };
+
+        Some(Stats {
+            min: durations[0],
+            median,
+            max: durations[n - 1],
+        })
+    }
+}
+
+/// Timing statistics for every phase of a solution over one or more runs.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Timings {
+    pub runs: usize,
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+impl Timings {
+    /// Summarises the samples of several runs of the same solution. A phase
+    /// only gets statistics if every sample measured it.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &[Sample]) -> Self {
+        fn phase(samples: &[Sample], f: impl Fn(&Sample) -> Option<Duration>) -> Option<Stats> {
+            let durations: Option<Vec<Duration>> = samples.iter().map(f).collect();
+            Stats::from_durations(durations?)
+        }
+
+        Timings {
+            runs: samples.len(),
+            parse: phase(samples, |s| s.parse),
+            part1: phase(samples, |s| s.part1),
+            part2: phase(samples, |s| s.part2),
+            total: Stats::from_durations(samples.iter().map(|s| s.total))
+                .expect("should have at least one sample"),
+        }
+    }
+
+    /// Returns the name and statistics of every measured phase, in the order
+    /// they are run.
+    pub fn phases(&self) -> impl Iterator<Item = (&'static str, Stats)> {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+            ("total", Some(self.total)),
+        ]
+        .into_iter()
+        .filter_map(|(name, stats)| Some((name, stats?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats_empty() {
+        assert_eq!(Stats::from_durations([]), None);
+    }
+
+    #[test]
+    fn stats_odd() {
+        let stats = Stats::from_durations([ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+    }
+
+    #[test]
+    fn stats_even() {
+        let stats = Stats::from_durations([ms(4), ms(1), ms(2), ms(8)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(8));
+    }
+
+    #[test]
+    fn timings_only_measured_phases() {
+        let samples = [
+            Sample {
+                total: ms(2),
+                ..Default::default()
+            },
+            Sample {
+                part1: Some(ms(1)),
+                total: ms(4),
+                ..Default::default()
+            },
+        ];
+        let timings = Timings::from_samples(&samples);
+
+        assert_eq!(timings.runs, 2);
+        assert!(timings.parse.is_none());
+        assert!(timings.part1.is_none());
+        assert_eq!(timings.total.median, ms(3));
+        assert_eq!(
+            timings.phases().map(|(name, _)| name).collect::<Vec<_>>(),
+            ["total"]
+        );
+    }
+}
@@ -0,0 +1,394 @@
+//! Lookup of the puzzle input for a solution.
+//!
+//! The input is taken from the first of these that exists:
+//! 1. A file given on the command line, or `-` for standard input.
+//! 2. `<year>/<day>.txt` inside the input directory, given by `--input-dir`
+//!    or the `AOC_INPUT_DIR` environment variable.
+//! 3. The input embedded in the binary.
+//!
+//! Several inputs can be given on the command line for a single day, either
+//! as files, directories or glob patterns, see [`expand`].
+//!
+//! The input directory doubles as a local cache of inputs. An input given on
+//! the command line can be saved there with `--save-input`, so it is picked
+//! up by later runs without having to name it, and without ever needing a
+//! session to download it.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::report::InputSource;
+
+/// Puzzle input together with where it came from.
+pub struct Input {
+    pub data: Cow<'static, [u8]>,
+    pub source: InputSource,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No input was found at any of the searched locations.
+    NotFound {
+        year: u32,
+        day: u32,
+        searched: Vec<String>,
+    },
+    /// An input was found but could not be read.
+    Read { path: PathBuf, err: io::Error },
+    /// An input could not be saved in the input directory.
+    Write { path: PathBuf, err: io::Error },
+    /// An input should be saved, but there is no input directory.
+    NoInputDir,
+    /// A glob pattern was invalid.
+    Pattern { pattern: String, reason: String },
+    /// A directory or glob pattern given on the command line held no files.
+    NoMatches { arg: PathBuf },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound {
+                year,
+                day,
+                searched,
+            } => {
+                write!(f, "no input found for {year} day {day}, looked in: ")?;
+                write!(f, "{}", searched.join(", "))
+            }
+            InputError::Read { path, err } => {
+                write!(
+                    f,
+                    "failed to read input file '{}': {err}",
+                    path.to_string_lossy()
+                )
+            }
+            InputError::Write { path, err } => {
+                write!(
+                    f,
+                    "failed to save input file '{}': {err}",
+                    path.to_string_lossy()
+                )
+            }
+            InputError::NoInputDir => write!(
+                f,
+                "no input directory to save the input in, set one with \
+                 --input-dir or AOC_INPUT_DIR"
+            ),
+            InputError::Pattern { pattern, reason } => {
+                write!(f, "invalid input pattern '{pattern}': {reason}")
+            }
+            InputError::NoMatches { arg } => {
+                write!(f, "no input files matched '{}'", arg.to_string_lossy())
+            }
+        }
+    }
+}
+
+/// Finds the input for solutions in the order described in the module
+/// documentation.
+#[derive(Debug, Default, Clone)]
+pub struct InputProvider {
+    /// Input file given on the command line, only used for a single day.
+    pub path: Option<PathBuf>,
+    /// Directory with inputs laid out as `<year>/<day>.txt`.
+    pub dir: Option<PathBuf>,
+}
+
+impl InputProvider {
+    /// Path of the input for the given day inside `dir`.
+    pub fn dir_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+        dir.join(year.to_string()).join(format!("{day}.txt"))
+    }
+
+    /// Returns the input for the given day. An empty embedded input is
+    /// treated as missing, as it is only a placeholder for a day that has
+    /// not been solved yet.
+    pub fn load(&self, year: u32, day: u32, embedded: &'static [u8]) -> Result<Input, InputError> {
+        let mut searched = Vec::new();
+
+        if let Some(path) = &self.path {
+            return if *path == *PathBuf::from("-") {
+                read(io::stdin(), path).map(|data| Input {
+                    data: data.into(),
+                    source: InputSource::Stdin,
+                })
+            } else {
+                read_file(path).map(|data| Input {
+                    data: data.into(),
+                    source: InputSource::Custom { path: path.clone() },
+                })
+            };
+        }
+
+        if let Some(dir) = &self.dir {
+            let path = Self::dir_path(dir, year, day);
+            if path.is_file() {
+                return read_file(&path).map(|data| Input {
+                    data: data.into(),
+                    source: InputSource::Custom { path },
+                });
+            }
+            searched.push(format!("'{}'", path.to_string_lossy()));
+        }
+
+        if !embedded.is_empty() {
+            return Ok(Input {
+                data: embedded.into(),
+                source: InputSource::Embedded,
+            });
+        }
+        searched.push("embedded input".to_owned());
+
+        Err(InputError::NotFound {
+            year,
+            day,
+            searched,
+        })
+    }
+
+    /// Saves `data` as the input for the given day in the input directory,
+    /// replacing any input saved before. Returns where it was saved.
+    pub fn save(&self, year: u32, day: u32, data: &[u8]) -> Result<PathBuf, InputError> {
+        let dir = self.dir.as_ref().ok_or(InputError::NoInputDir)?;
+        let path = Self::dir_path(dir, year, day);
+        let write_err = |err| InputError::Write {
+            path: path.clone(),
+            err,
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(write_err)?;
+        }
+        std::fs::write(&path, data).map_err(write_err)?;
+        Ok(path)
+    }
+}
+
+/// Expands the inputs given on the command line into a list of input files.
+/// Directories are replaced by the files directly inside them and paths that
+/// do not exist are treated as glob patterns, both sorted by name. Other paths,
+/// including `-` for standard input, are kept as they are. A directory or
+/// pattern without any files is an error, rather than silently running
+/// with no inputs.
+pub fn expand(paths: &[PathBuf]) -> Result<Vec<PathBuf>, InputError> {
+    let mut expanded = Vec::new();
+
+    for path in paths {
+        if path.is_dir() {
+            let entries = std::fs::read_dir(path).map_err(|err| InputError::Read {
+                path: path.clone(),
+                err,
+            })?;
+            let mut files: Vec<PathBuf> = entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect();
+            if files.is_empty() {
+                return Err(InputError::NoMatches { arg: path.clone() });
+            }
+            files.sort();
+            expanded.extend(files);
+        } else if path.exists() || *path == *PathBuf::from("-") {
+            expanded.push(path.clone());
+        } else {
+            let pattern = path.to_string_lossy();
+            let pattern_error = |reason: String| InputError::Pattern {
+                pattern: pattern.to_string(),
+                reason,
+            };
+
+            let matches = glob::glob(&pattern).map_err(|err| pattern_error(err.to_string()))?;
+            let mut files: Vec<PathBuf> = matches
+                .filter_map(Result::ok)
+                .filter(|path| path.is_file())
+                .collect();
+            if files.is_empty() {
+                // Not a pattern after all, let reading the file report the error.
+                if !pattern.contains(['*', '?', '[']) {
+                    expanded.push(path.clone());
+                    continue;
+                }
+                return Err(InputError::NoMatches { arg: path.clone() });
+            }
+            files.sort();
+            expanded.extend(files);
+        }
+    }
+
+    Ok(expanded)
+}
+
+fn read(mut reader: impl Read, path: &Path) -> Result<Vec<u8>, InputError> {
+    let mut buf = Vec::new();
+    reader
+        .read_to_end(&mut buf)
+        .map_err(|err| InputError::Read {
+            path: path.to_owned(),
+            err,
+        })?;
+    Ok(buf)
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, InputError> {
+    let file = File::open(path).map_err(|err| InputError::Read {
+        path: path.to_owned(),
+        err,
+    })?;
+    read(file, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("chrjen_aoc_{name}_{}", std::process::id()));
+        fs::create_dir_all(dir.join("2022")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn embedded_by_default() {
+        let input = InputProvider::default().load(2022, 1, b"embedded").unwrap();
+        assert_eq!(&*input.data, b"embedded");
+        assert_eq!(input.source, InputSource::Embedded);
+    }
+
+    #[test]
+    fn dir_before_embedded() {
+        let dir = temp_dir("dir_before_embedded");
+        fs::write(dir.join("2022").join("1.txt"), b"from dir").unwrap();
+
+        let provider = InputProvider {
+            path: None,
+            dir: Some(dir.clone()),
+        };
+        let input = provider.load(2022, 1, b"embedded").unwrap();
+        assert_eq!(&*input.data, b"from dir");
+
+        // Days missing from the directory fall back to the embedded input.
+        let input = provider.load(2022, 2, b"embedded").unwrap();
+        assert_eq!(&*input.data, b"embedded");
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn path_before_dir() {
+        let dir = temp_dir("path_before_dir");
+        fs::write(dir.join("2022").join("1.txt"), b"from dir").unwrap();
+        fs::write(dir.join("custom"), b"from path").unwrap();
+
+        let provider = InputProvider {
+            path: Some(dir.join("custom")),
+            dir: Some(dir.clone()),
+        };
+        let input = provider.load(2022, 1, b"embedded").unwrap();
+        assert_eq!(&*input.data, b"from path");
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn save_to_dir() {
+        let dir = temp_dir("save_to_dir");
+        let provider = InputProvider {
+            path: None,
+            dir: Some(dir.clone()),
+        };
+        let path = provider.save(2016, 3, b"saved").unwrap();
+        assert_eq!(path, dir.join("2016").join("3.txt"));
+
+        // Later runs without an input given find the saved one.
+        let input = provider.load(2016, 3, b"embedded").unwrap();
+        assert_eq!(&*input.data, b"saved");
+
+        let provider = InputProvider::default();
+        assert!(matches!(
+            provider.save(2016, 3, b"saved"),
+            Err(InputError::NoInputDir)
+        ));
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn not_found() {
+        let provider = InputProvider {
+            path: None,
+            dir: Some(PathBuf::from("no_such_dir")),
+        };
+        let Err(err) = provider.load(2022, 1, b"") else {
+            panic!("should not find any input");
+        };
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "no input found for 2022 day 1, looked in: '{}', embedded input",
+                Path::new("no_such_dir")
+                    .join("2022")
+                    .join("1.txt")
+                    .display()
+            )
+        );
+    }
+
+    #[test]
+    fn expand_inputs() {
+        let dir = temp_dir("expand_inputs");
+        for name in ["b.txt", "a.txt", "c.in"] {
+            fs::write(dir.join("2022").join(name), b"").unwrap();
+        }
+
+        let year_dir = dir.join("2022");
+        assert_eq!(
+            expand(std::slice::from_ref(&year_dir)).unwrap(),
+            [
+                year_dir.join("a.txt"),
+                year_dir.join("b.txt"),
+                year_dir.join("c.in")
+            ]
+        );
+        assert_eq!(
+            expand(&[year_dir.join("*.txt"), PathBuf::from("-")]).unwrap(),
+            [
+                year_dir.join("a.txt"),
+                year_dir.join("b.txt"),
+                PathBuf::from("-")
+            ]
+        );
+        assert!(matches!(
+            expand(&[year_dir.join("*.md")]),
+            Err(InputError::NoMatches { .. })
+        ));
+        fs::create_dir_all(dir.join("empty")).unwrap();
+        assert_eq!(
+            expand(&[dir.join("empty")]).unwrap_err().to_string(),
+            format!("no input files matched '{}'", dir.join("empty").display())
+        );
+        assert_eq!(
+            expand(&[year_dir.join("missing")]).unwrap(),
+            [year_dir.join("missing")]
+        );
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn missing_path_is_an_error() {
+        let provider = InputProvider {
+            path: Some(PathBuf::from("no_such_file")),
+            dir: None,
+        };
+        assert!(matches!(
+            provider.load(2022, 1, b"embedded"),
+            Err(InputError::Read { .. })
+        ));
+    }
+}
@@ -5,153 +5,577 @@
 //! programming language you like.
 //! Link: https://adventofcode.com/
 
-use std::fs::File;
+mod bench;
+mod input;
+mod memory;
+mod panic;
+mod parallel;
+mod registry;
+mod report;
+mod scaffold;
+mod status;
+mod verify;
+mod visualise;
+mod watchdog;
+
 use std::io;
-use std::io::Read;
-use std::path::PathBuf;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use bench::Timings;
+use input::{Input, InputError, InputProvider};
+use registry::{ALL_YEARS, Solution, solutions_for_year};
+use report::{Failure, FailureKind, Format, InputSource, Record, Reporter};
+use verify::{Answers, Verifier};
 
-use clap::Parser;
-use common::Solution;
+use clap::{Parser, Subcommand};
+use common::ocr;
+use common_v2::Output;
 use crossterm::execute;
 use crossterm::style::{Color, SetForegroundColor};
 
-use solutions_2015 as s15;
-use solutions_2016 as s16;
-use solutions_2022 as s22;
-use solutions_2023 as s23;
-use solutions_2024 as s24;
+#[global_allocator]
+static ALLOCATOR: memory::TrackingAllocator = memory::TrackingAllocator;
 
-const ALL_YEARS: [u32; 5] = [2015, 2016, 2022, 2023, 2024];
-
-fn solutions_for_year(year: u32) -> Option<&'static [Option<Solution<'static>>]> {
-    match year {
-        2015 => Some(s15::SOLUTIONS),
-        2016 => Some(s16::SOLUTIONS),
-        2022 => Some(s22::SOLUTIONS),
-        2023 => Some(s23::SOLUTIONS),
-        2024 => Some(s24::SOLUTIONS),
-        _ => None,
-    }
-}
-
-#[derive(Parser, Debug)]
-#[command(version, about, long_about)]
+#[derive(Parser, Debug, Clone)]
+#[command(version, about, long_about, args_conflicts_with_subcommands = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Year to display solutions. Default all years.
     year: Option<u32>,
 
     /// Day to display solutions. Default all days.
     day: Option<u32>,
 
-    /// Custom solution input.
-    input: Option<PathBuf>,
+    /// Custom solution input, or `-` to read from standard input. Several
+    /// files, directories or glob patterns can be given to solve the day
+    /// once for every input.
+    #[arg(value_name = "INPUT")]
+    inputs: Vec<PathBuf>,
+
+    /// Directory with inputs named `<year>/<day>.txt`, used instead of the
+    /// embedded inputs when present.
+    #[arg(long, global = true, value_name = "DIR", env = "AOC_INPUT_DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Save the input given on the command line in the input directory, so
+    /// later runs of the day use it without naming it.
+    #[arg(long, requires = "inputs")]
+    save_input: bool,
+
+    /// Show how long each solution took to run. Parsing and each part are
+    /// only timed separately for solutions using `common_v2`. The older
+    /// solutions solve both parts in one go, so only their total is shown.
+    #[arg(short, long)]
+    time: bool,
+
+    /// Run each solution N times and show the min, median and max time. Like
+    /// `--time`, only solutions using `common_v2` are timed per phase.
+    #[arg(short, long, value_name = "N")]
+    bench: Option<NonZeroUsize>,
+
+    /// Show the peak heap memory used by each solution. Memory is counted
+    /// for the whole process, so it is only accurate without `--jobs`.
+    #[arg(short, long)]
+    memory: bool,
+
+    /// Give up on a solution after this many seconds and report it as timed
+    /// out.
+    #[arg(long, global = true, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+
+    /// Show answers drawn in block letters as they are drawn, instead of as
+    /// the letters they show.
+    #[arg(long)]
+    raw_art: bool,
+
+    /// Only solve the given part.
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Number of solutions to run in parallel. Timings are less reliable
+    /// when running more than one at a time.
+    #[arg(short, long, global = true, value_name = "N", default_value = "1")]
+    jobs: NonZeroUsize,
+
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Play back the frames drawn by the solution as an animation once it is
+    /// done. Only some days draw frames. Inputs are run one at a time, with
+    /// the frames of each input following the previous one.
+    #[arg(long, requires = "day", conflicts_with = "bench")]
+    visualise: bool,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+enum Command {
+    /// Check the answers of the solutions against a file of known answers.
+    Verify {
+        /// Year to verify. Default all years.
+        year: Option<u32>,
+
+        /// Day to verify. Default all days.
+        day: Option<u32>,
+
+        /// TOML file with the known answers.
+        #[arg(short, long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Add a new day from the template, creating the year if needed. Run
+    /// from the root of the repository.
+    New {
+        /// Year of the puzzle.
+        year: u32,
+
+        /// Day of the puzzle.
+        day: u32,
+    },
+}
+
+impl Args {
+    /// Number of times each solution should be run.
+    fn runs(&self) -> usize {
+        self.bench.map_or(1, NonZeroUsize::get)
+    }
+
+    /// Whether timings should be shown.
+    fn timed(&self) -> bool {
+        self.time || self.bench.is_some()
+    }
+}
+
+/// Parses a positive number of seconds, which may have a fraction.
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s.parse().map_err(|_| format!("'{s}' is not a number"))?;
+    match Duration::try_from_secs_f64(secs) {
+        Ok(duration) if !duration.is_zero() => Ok(duration),
+        _ => Err(format!("'{s}' is not a positive number of seconds")),
+    }
 }
 
 fn main() {
-    let mut stdout = io::stdout();
-    let args = Args::parse();
+    let mut args = Args::parse();
 
-    if let Some(year) = args.year {
-        run_specific_year(year, args.day, args.input);
-        exit(0);
+    if args.memory {
+        memory::enable();
+    }
+    if args.format == Format::Text {
+        status::install();
+    }
+    if args.visualise {
+        // Frames from solutions running at the same time would be mixed up.
+        args.jobs = NonZeroUsize::MIN;
+        visualise::install();
     }
 
-    for year in ALL_YEARS {
-        execute!(stdout, SetForegroundColor(Color::Cyan)).ok();
-        println!("------ YEAR {} ------", year);
-        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    match &args.command {
+        Some(Command::Verify { year, day, answers }) => verify(&args, *year, *day, answers),
+        Some(Command::New { year, day }) => new_day(*year, *day),
+        None => {}
+    }
+
+    let mut reporter = report::reporter(args.format, args.timed(), args.memory);
+    let failed = run(&args, reporter.as_mut(), args.year, args.day, &args.inputs);
+    reporter.finish();
+
+    if args.visualise {
+        visualise::play();
+    }
 
-        run_specific_year(year, None, None);
+    if !failed.is_empty() {
+        eprintln_failures(&failed);
+        exit(1);
     }
 }
 
-fn run_specific_year(year: u32, day: Option<u32>, input: Option<PathBuf>) {
-    if let Some(solutions) = solutions_for_year(year) {
-        if let Some(day) = day {
-            run_specific_day(solutions, day, input);
+/// Prints a summary of the solutions that failed to standard error, so it
+/// does not get mixed into machine-readable output.
+fn eprintln_failures(failed: &[Record]) {
+    let mut stderr = io::stderr();
+    execute!(stderr, SetForegroundColor(Color::Red)).ok();
+    eprintln!("------ {} FAILED ------", failed.len());
+    for record in failed {
+        eprint!("{} day {:>2}", record.year, record.day);
+        if let Some(path) = record.input.path() {
+            eprint!(" ({})", path.to_string_lossy());
         }
-
-        for solution in solutions.iter().flatten() {
-            println_solution(solution);
+        match &record.failure {
+            Some(failure) => eprintln!(": {} {failure}", failure.kind.label()),
+            None => {
+                let errors = [&record.part1_error, &record.part2_error];
+                let errors: Vec<String> = (1..)
+                    .zip(errors)
+                    .filter_map(|(part, error)| Some(format!("ERROR ({part}) {}", error.as_ref()?)))
+                    .collect();
+                eprintln!(": {}", errors.join(", "));
+            }
         }
-    } else {
-        eprintln!("solutions for year {year} not found.");
     }
+    execute!(stderr, SetForegroundColor(Color::Reset)).ok();
 }
 
-fn run_specific_day(solutions: &[Option<Solution>], day: u32, path: Option<PathBuf>) -> ! {
-    let idx = (day - 1) as usize;
-
-    if (0..solutions.len()).contains(&idx) && solutions[idx].is_some() {
-        let solution = solutions[idx].as_ref().unwrap();
-        let mut input: &[u8] = solution.input;
-        let mut buf = Vec::new();
+/// Runs the solutions for the given year and day, or all of them if not
+/// given, and hands the results to the reporter. Returns the records of the
+/// solutions that failed.
+fn run(
+    args: &Args,
+    reporter: &mut dyn Reporter,
+    year: Option<u32>,
+    day: Option<u32>,
+    inputs: &[PathBuf],
+) -> Vec<Record> {
+    let provider = InputProvider {
+        path: None,
+        dir: args.input_dir.clone(),
+    };
 
-        if let Some(path) = path {
-            // Read in custom input from user.
-            if *path == *PathBuf::from("-") {
-                // Read input from stdin.
-                let result = io::stdin().read_to_end(&mut buf);
-                if result.is_err() {
-                    println!("failed to read input file '{}'", &path.to_string_lossy());
+    match (year, day) {
+        (Some(year), Some(day)) => {
+            let solutions = find_year(year);
+            let paths = match input::expand(inputs) {
+                Ok(paths) => paths,
+                Err(err) => {
+                    eprintln!("{err}");
                     exit(1);
                 }
+            };
+
+            if args.save_input && paths.len() > 1 {
+                eprintln!("--save-input only works with a single input.");
+                exit(1);
+            }
+
+            if paths.len() > 1 {
+                let mut records =
+                    run_specific_day_inputs(args, &provider, &solutions, year, day, &paths);
+                reporter.inputs(&records);
+                records.retain(Record::failed);
+                records
             } else {
-                // Read input from file.
-                let file = File::open(&path);
-                if file.is_err() {
-                    println!("failed to open input file '{}'", &path.to_string_lossy());
-                    exit(1);
+                let provider = InputProvider {
+                    path: paths.into_iter().next(),
+                    ..provider
+                };
+                let record = run_specific_day(args, &provider, &solutions, year, day);
+                reporter.record(&record);
+                Vec::from_iter(record.failed().then_some(record))
+            }
+        }
+        (Some(year), None) => run_years(args, reporter, &provider, &[year], false),
+        (None, _) => run_years(args, reporter, &provider, &ALL_YEARS, true),
+    }
+}
+
+/// Runs the solutions and compares their answers with the known answers,
+/// exiting with a non-zero exit code if any answer is wrong.
+fn verify(args: &Args, year: Option<u32>, day: Option<u32>, answers: &Path) -> ! {
+    let answers = match Answers::load(answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!(
+                "failed to load answers file '{}': {err}",
+                answers.to_string_lossy()
+            );
+            exit(2);
+        }
+    };
+
+    let mut verifier = Verifier::new(answers, year, day, args.part);
+    run(args, &mut verifier, year, day, &[]);
+    verifier.finish();
+
+    exit(if verifier.success() { 0 } else { 1 });
+}
+
+/// Adds a new day to the repository in the current directory.
+fn new_day(year: u32, day: u32) -> ! {
+    match scaffold::new_day(Path::new("."), year, day) {
+        Ok(changes) => {
+            for (path, change) in changes {
+                let action = match change {
+                    scaffold::Change::Create => "created",
+                    scaffold::Change::Edit => "updated",
+                };
+                println!("{action} {}", path.to_string_lossy());
+            }
+            exit(0);
+        }
+        Err(err) => {
+            let mut stderr = io::stderr();
+            execute!(stderr, SetForegroundColor(Color::Red)).ok();
+            eprintln!("{err}");
+            execute!(stderr, SetForegroundColor(Color::Reset)).ok();
+            exit(1);
+        }
+    }
+}
+
+/// Runs all solutions for the given years with their embedded input,
+/// spreading them over `args.jobs` threads. Results are reported in year
+/// and day order regardless of which finish first. Returns the records of the
+/// solutions that failed.
+fn run_years(
+    args: &Args,
+    reporter: &mut dyn Reporter,
+    provider: &InputProvider,
+    years: &[u32],
+    headers: bool,
+) -> Vec<Record> {
+    let mut tasks = Vec::new();
+    for &year in years {
+        for solution in find_year(year) {
+            tasks.push((year, solution.day(), solution));
+        }
+    }
+
+    let mut current_year = None;
+    let mut records = Vec::new();
+    let mut failed = Vec::new();
+
+    parallel::run_ordered(
+        args.jobs,
+        &tasks,
+        |&(year, day, solution)| {
+            let input = provider.load(year, day, solution.input())?;
+            Ok(run_solution_guarded(args, &solution, year, day, input))
+        },
+        |record: Result<Record, InputError>| {
+            let record = match record {
+                Ok(record) => record,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return;
                 }
+            };
 
-                let result = file.unwrap().read_to_end(&mut buf);
-                if result.is_err() {
-                    println!("failed to read input file '{}'", &path.to_string_lossy());
-                    exit(1);
+            if current_year != Some(record.year) {
+                if let Some(year) = current_year {
+                    reporter.year_end(year, &records);
+                    records.clear();
                 }
+                if headers {
+                    reporter.year_start(record.year);
+                }
+                current_year = Some(record.year);
+            }
+
+            reporter.record(&record);
+            if record.failed() {
+                failed.push(record.clone());
             }
-            input = &buf;
+            records.push(record);
+        },
+    );
+
+    if let Some(year) = current_year {
+        reporter.year_end(year, &records);
+    }
+
+    failed
+}
+
+/// Returns the solutions for the given year, exiting if there are none.
+fn find_year(year: u32) -> Vec<Solution> {
+    match solutions_for_year(year) {
+        Some(solutions) => solutions,
+        None => {
+            eprintln!("solutions for year {year} not found.");
+            exit(2);
         }
+    }
+}
 
-        println_solution_with_input(solution, input);
-        exit(0);
-    } else {
-        eprintln!("solution for day {day} not found.");
-        exit(2);
+/// Returns the solution for the given day, exiting if there is none.
+fn find_solution(solutions: &[Solution], day: u32) -> Solution {
+    match solutions.iter().find(|solution| solution.day() == day) {
+        Some(solution) => *solution,
+        None => {
+            eprintln!("solution for day {day} not found.");
+            exit(2);
+        }
     }
 }
 
-fn println_solution(solution: &Solution) {
-    println_solution_with_input(solution, solution.input);
+fn run_specific_day(
+    args: &Args,
+    provider: &InputProvider,
+    solutions: &[Solution],
+    year: u32,
+    day: u32,
+) -> Record {
+    let solution = find_solution(solutions, day);
+    let input = match provider.load(year, day, solution.input()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            exit(1);
+        }
+    };
+
+    if args.save_input {
+        match provider.save(year, day, &input.data) {
+            Ok(path) => eprintln!("saved input to '{}'.", path.to_string_lossy()),
+            Err(err) => {
+                eprintln!("{err}");
+                exit(1);
+            }
+        }
+    }
+
+    run_solution_guarded(args, &solution, year, day, input)
 }
 
-fn println_solution_with_input(solution: &Solution, input: &[u8]) {
-    let mut stdout = io::stdout();
+/// Solves a single day once for each of the input files. An input that can not
+/// be read or makes the solution panic is reported as a failure without
+/// stopping the other inputs.
+fn run_specific_day_inputs(
+    args: &Args,
+    provider: &InputProvider,
+    solutions: &[Solution],
+    year: u32,
+    day: u32,
+    paths: &[PathBuf],
+) -> Vec<Record> {
+    let solution = find_solution(solutions, day);
+    let mut records = Vec::with_capacity(paths.len());
+
+    parallel::run_ordered(
+        args.jobs,
+        paths,
+        |path| {
+            let provider = InputProvider {
+                path: Some(path.clone()),
+                ..provider.clone()
+            };
+            match provider.load(year, day, solution.input()) {
+                Ok(input) => run_solution_guarded(args, &solution, year, day, input),
+                Err(err) => Record {
+                    failure: Some(Failure::input(err)),
+                    ..Record::empty(
+                        &solution,
+                        year,
+                        day,
+                        InputSource::Custom { path: path.clone() },
+                    )
+                },
+            }
+        },
+        |record| records.push(record),
+    );
 
-    let (part1, part2) = (solution.solve)(input);
-    execute!(stdout, SetForegroundColor(Color::Green)).ok();
-    println!("--- {} ---", solution.name);
+    records
+}
 
-    execute!(stdout, SetForegroundColor(Color::White)).ok();
+/// Runs the solution as many times as requested by `args`, returning the
+/// answers from the first run and, if requested, the timings of all runs.
+fn run_solution(args: &Args, solution: &Solution, year: u32, day: u32, input: Input) -> Record {
+    let runs = args.runs();
+    let mut samples = Vec::with_capacity(runs);
+    let memory_baseline = args.memory.then(memory::reset_peak);
 
-    // Output solution part 1.
-    let mut lines = part1.lines();
-    if let Some(first_line) = lines.next() {
-        println!("(1) {}", first_line);
+    let (part1, part2, sample) = match solution.run(&input.data, args.part) {
+        Ok(answers) => answers,
+        Err(err) => {
+            return Record {
+                failure: Some(Failure::parse(err, &input.data, &input.source)),
+                ..Record::empty(solution, year, day, input.source)
+            };
+        }
+    };
+    samples.push(sample);
+
+    for _ in 1..runs {
+        // The input parsed the first time, so it does so every time.
+        if let Ok((_, _, sample)) = std::hint::black_box(solution.run(&input.data, args.part)) {
+            samples.push(sample);
+        }
     }
-    for line in lines {
-        println!("    {}", line);
+
+    let (part1, part1_error) = answer_or_error(part1);
+    let (part2, part2_error) = answer_or_error(part2);
+    let (part1, part2) = if args.raw_art {
+        (part1, part2)
+    } else {
+        let letters = |answer: Option<String>| answer.map(|a| ocr::normalise(&a).into_owned());
+        (letters(part1), letters(part2))
+    };
+
+    Record {
+        part1,
+        part2,
+        part1_error,
+        part2_error,
+        timings: args.timed().then(|| Timings::from_samples(&samples)),
+        peak_memory: memory_baseline.map(memory::peak_since),
+        ..Record::empty(solution, year, day, input.source)
     }
+}
 
-    // Output solution part 2.
-    let mut lines = part2.lines();
-    if let Some(first_line) = lines.next() {
-        println!("(2) {}", first_line);
+/// Splits the output of a part into its answer or the error it failed with.
+fn answer_or_error(output: Option<Output>) -> (Option<String>, Option<String>) {
+    match output {
+        Some(Output::Error(err)) => (None, Some(err.to_string())),
+        output => (output.map(|answer| answer.to_string()), None),
     }
-    for line in lines {
-        println!("    {}", line);
+}
+
+/// Like [`run_solution`], but a solution that panics or runs past
+/// `args.timeout` is returned as a failed record instead.
+fn run_solution_guarded(
+    args: &Args,
+    solution: &Solution,
+    year: u32,
+    day: u32,
+    input: Input,
+) -> Record {
+    status::start(year, day);
+    let source = input.source.clone();
+    let guarded = {
+        let (args, solution, source) = (args.clone(), *solution, source.clone());
+        move || {
+            panic::catch(|| run_solution(&args, &solution, year, day, input)).unwrap_or_else(
+                |failure| Record {
+                    failure: Some(failure),
+                    ..Record::empty(&solution, year, day, source)
+                },
+            )
+        }
+    };
+
+    let record = match args.timeout {
+        None => guarded(),
+        Some(timeout) => watchdog::run(timeout, guarded).unwrap_or_else(|| Record {
+            failure: Some(Failure::timeout(timeout)),
+            ..Record::empty(solution, year, day, source)
+        }),
+    };
+    status::clear();
+    if args.memory
+        && record
+            .failure
+            .as_ref()
+            .is_some_and(|failure| failure.kind == FailureKind::Timeout)
+    {
+        eprintln_abandoned_memory();
     }
+    record
+}
 
-    execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+/// Notes once that the solutions after a timeout share the process with the
+/// one left running, so their memory peaks may include its allocations.
+fn eprintln_abandoned_memory() {
+    static NOTED: AtomicBool = AtomicBool::new(false);
+    if !NOTED.swap(true, Ordering::Relaxed) {
+        eprintln!(
+            "note: a timed out solution keeps running, \
+             so later memory peaks may include its allocations."
+        );
+    }
 }
@@ -0,0 +1,115 @@
+//! Measurement of the peak heap memory used by solutions.
+//!
+//! [`TrackingAllocator`] wraps the system allocator and, once tracking is
+//! enabled, counts the bytes currently allocated and the highest count seen.
+//! Memory is counted for the whole process, so the peak of a solution also
+//! includes solutions running at the same time with `--jobs`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated since tracking was enabled. Memory allocated before that
+/// and freed afterwards can make this negative.
+static CURRENT: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// Global allocator counting allocated bytes while tracking is enabled.
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn add(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            let current = CURRENT.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+
+    fn sub(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            CURRENT.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::sub(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::add(new_size);
+            Self::sub(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Starts a new measurement by resetting the peak to the bytes currently
+/// allocated. Returns the baseline to pass to [`peak_since`].
+pub fn reset_peak() -> isize {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    current
+}
+
+/// Highest number of bytes allocated on top of `baseline` since the last
+/// call to [`reset_peak`].
+pub fn peak_since(baseline: isize) -> u64 {
+    (PEAK.load(Ordering::Relaxed) - baseline).max(0) as u64
+}
+
+/// Formats a number of bytes using binary units, like `12.34 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_units() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.00 MiB");
+        assert_eq!(format_bytes(3 << 40), "3.00 TiB");
+    }
+}
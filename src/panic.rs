@@ -0,0 +1,100 @@
+//! Panic boundary around solver invocations, so a solver panicking does not
+//! bring down the whole run.
+//!
+//! While inside [`catch`], a panic hook records where the panic happened and
+//! keeps the default hook from printing the panic, as it is reported as a
+//! [`Failure`] instead. Panics outside of [`catch`] are printed as usual.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::report::{Failure, FailureKind};
+
+thread_local! {
+    /// Whether the current thread is inside [`catch`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Location of the last panic caught on the current thread.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LOCATION.set(info.location().map(ToString::to_string));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, catching any panic and returning it as a [`Failure`].
+///
+/// The location is only known for panics on the calling thread. Panics
+/// propagated from other threads, like rayon's workers, only keep their
+/// message.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_hook();
+
+    let was_catching = CATCHING.replace(true);
+    LOCATION.set(None);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+
+    result.map_err(|payload| Failure {
+        kind: FailureKind::Panic,
+        message: payload_message(payload.as_ref()),
+        location: LOCATION.take(),
+        snippet: None,
+    })
+}
+
+/// Extracts the message from a panic payload, which is a `&str` or a
+/// `String` for panics raised by `panic!` and friends.
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_ok() {
+        assert_eq!(catch(|| 1 + 1), Ok(2));
+    }
+
+    #[test]
+    fn catch_panic() {
+        let failure = catch(|| panic!("got unknown instruction '{}'", "nop")).unwrap_err();
+        assert_eq!(failure.kind, FailureKind::Panic);
+        assert_eq!(failure.message, "got unknown instruction 'nop'");
+        let location = failure.location.unwrap();
+        assert!(location.starts_with(file!()), "{location}");
+
+        let failure = catch(|| "x".parse::<u8>().expect("static message")).unwrap_err();
+        assert!(failure.message.starts_with("static message: "));
+    }
+
+    #[test]
+    fn catch_nested() {
+        let outer = catch(|| {
+            let inner = catch(|| panic!("inner"));
+            assert!(inner.is_err());
+            panic!("outer");
+        });
+        assert_eq!(outer.unwrap_err().message, "outer");
+    }
+}
@@ -0,0 +1,104 @@
+//! Running independent tasks on several threads while still handling their
+//! results in a deterministic order.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Stack size of the worker threads. Some solutions recurse deeply, so this is
+/// kept well above the default of spawned threads.
+pub const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Runs `f` on every task using up to `jobs` threads and calls `on_result`
+/// with the results in the same order as the tasks. Results that finish early
+/// are held back until all tasks before them are done.
+///
+/// With a single job the tasks are run one after another on the calling
+/// thread.
+pub fn run_ordered<T, R, F>(jobs: NonZeroUsize, tasks: &[T], f: F, mut on_result: impl FnMut(R))
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if jobs.get() == 1 {
+        tasks.iter().map(&f).for_each(on_result);
+        return;
+    }
+
+    let next_task = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+
+        for _ in 0..jobs.get().min(tasks.len()) {
+            let tx = tx.clone();
+            let (f, next_task) = (&f, &next_task);
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    loop {
+                        let idx = next_task.fetch_add(1, Ordering::Relaxed);
+                        let Some(task) = tasks.get(idx) else {
+                            break;
+                        };
+                        if tx.send((idx, f(task))).is_err() {
+                            break;
+                        }
+                    }
+                })
+                .expect("should be able to spawn worker thread");
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_result = 0;
+        for (idx, result) in rx {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&next_result) {
+                on_result(result);
+                next_result += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn results_in_task_order() {
+        let tasks: Vec<u64> = (0..20).collect();
+        let mut results = Vec::new();
+
+        run_ordered(
+            NonZeroUsize::new(4).unwrap(),
+            &tasks,
+            |&n| {
+                // Make earlier tasks finish later.
+                thread::sleep(Duration::from_millis(20 - n));
+                n * n
+            },
+            |result| results.push(result),
+        );
+
+        assert_eq!(results, tasks.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn single_job() {
+        let mut results = Vec::new();
+        run_ordered(
+            NonZeroUsize::MIN,
+            &[1, 2, 3],
+            |n| n + 1,
+            |result| results.push(result),
+        );
+        assert_eq!(results, [2, 3, 4]);
+    }
+}
@@ -0,0 +1,180 @@
+//! Registry of all solutions known to the runner.
+//!
+//! Older years are written against the first version of the common crate
+//! while newer years implement the [`common_v2::Solver`] trait. Both are
+//! wrapped in [`Solution`] so the runner can treat them the same way.
+
+use std::time::Instant;
+
+use common_v2::{DynSolver, LegacyAdapter, Output, ParseError, ParseResult};
+
+use crate::bench::Sample;
+
+use solutions_2015 as s15;
+use solutions_2016 as s16;
+use solutions_2022 as s22;
+use solutions_2023 as s23;
+use solutions_2024 as s24;
+use solutions_2025 as s25;
+
+pub const ALL_YEARS: [u32; 6] = [2015, 2016, 2022, 2023, 2024, 2025];
+
+/// Answers for part 1 and 2, if solved, and how long it took.
+pub type Answers = (Option<Output>, Option<Output>, Sample);
+
+/// A solution for a single day.
+#[derive(Clone, Copy)]
+pub enum Solution {
+    /// A solution written against the first version of the common crate.
+    Legacy(&'static common::Solution<'static>),
+    /// A solution implementing [`common_v2::Solver`].
+    Parts(DynSolver),
+}
+
+impl Solution {
+    pub fn day(&self) -> u32 {
+        match self {
+            Solution::Legacy(solution) => solution.day,
+            Solution::Parts(solver) => solver.day,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Solution::Legacy(solution) => solution.name,
+            Solution::Parts(solver) => (solver.title)(),
+        }
+    }
+
+    /// The puzzle input embedded in the binary.
+    pub fn input(&self) -> &'static [u8] {
+        match self {
+            Solution::Legacy(solution) => solution.input,
+            Solution::Parts(solver) => (solver.input)(),
+        }
+    }
+
+    /// Parses the input, returning the state used to solve each part. Legacy
+    /// solutions solve both parts here and can only fail by panicking.
+    pub fn initial(&self, input: &[u8]) -> ParseResult {
+        match self {
+            Solution::Legacy(solution) => Ok(Box::new(LegacyAdapter::new(solution.solve, input))),
+            Solution::Parts(solver) => (solver.initial)(input),
+        }
+    }
+
+    /// Solves the given part, or both parts if `None`, timing each phase
+    /// separately if the solution supports it. Legacy solutions always solve
+    /// both parts, so the answer for the other part is discarded.
+    pub fn run(&self, input: &[u8], part: Option<u8>) -> Result<Answers, ParseError> {
+        let start = Instant::now();
+        let state = self.initial(input)?;
+        let parse = start.elapsed();
+
+        let timed = |solve: &dyn Fn() -> Output, n: u8| {
+            part.is_none_or(|part| part == n).then(|| {
+                let start = Instant::now();
+                let answer = solve();
+                (answer, start.elapsed())
+            })
+        };
+        let part1 = timed(&|| state.part1(), 1);
+        let part2 = timed(&|| state.part2(), 2);
+
+        let total = start.elapsed();
+
+        let sample = match self {
+            // The phases of legacy solutions can not be told apart.
+            Solution::Legacy(_) => Sample {
+                total,
+                ..Default::default()
+            },
+            Solution::Parts(_) => Sample {
+                parse: Some(parse),
+                part1: part1.as_ref().map(|(_, time)| *time),
+                part2: part2.as_ref().map(|(_, time)| *time),
+                total,
+            },
+        };
+
+        Ok((
+            part1.map(|(answer, _)| answer),
+            part2.map(|(answer, _)| answer),
+            sample,
+        ))
+    }
+}
+
+/// Returns the solutions for the given year sorted by day, or `None` if
+/// there are no solutions for the year.
+///
+/// Each year crate checks at compile time that its solutions are for that
+/// year and that no day is registered twice.
+pub fn solutions_for_year(year: u32) -> Option<Vec<Solution>> {
+    let mut solutions: Vec<Solution> = match year {
+        2015 => s15::SOLUTIONS.iter().map(Solution::Legacy).collect(),
+        2016 => s16::SOLUTIONS.iter().map(Solution::Legacy).collect(),
+        2022 => s22::SOLUTIONS.iter().map(Solution::Legacy).collect(),
+        2023 => s23::SOLUTIONS.iter().map(Solution::Legacy).collect(),
+        2024 => s24::SOLUTIONS.iter().map(Solution::Legacy).collect(),
+        2025 => s25::SOLUTIONS
+            .iter()
+            .copied()
+            .map(Solution::Parts)
+            .collect(),
+        _ => return None,
+    };
+    solutions.sort_by_key(Solution::day);
+    Some(solutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    /// Names of the directories in `dir` that match `pattern`.
+    fn dirs(dir: &Path, pattern: impl Fn(&str) -> bool) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_dir())
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .filter(|name| pattern(name))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn every_year_registered() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let is_year = |name: &str| name.len() == 4 && name.bytes().all(|b| b.is_ascii_digit());
+        let years: Vec<String> = ALL_YEARS.iter().map(u32::to_string).collect();
+        assert_eq!(dirs(root, is_year), years);
+    }
+
+    #[test]
+    fn every_day_registered() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for year in ALL_YEARS {
+            let registered: Vec<String> = solutions_for_year(year)
+                .unwrap()
+                .iter()
+                .map(|solution| format!("day{}", solution.day()))
+                .collect();
+            let is_day = |name: &str| {
+                name.strip_prefix("day")
+                    .is_some_and(|day| day.parse::<u32>().is_ok())
+            };
+            for day in dirs(&root.join(year.to_string()).join("src"), is_day) {
+                assert!(
+                    registered.contains(&day),
+                    "{year} {day} is not in the SOLUTIONS of the year"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,718 @@
+//! Output of solution results in the different formats supported by the
+//! runner.
+//!
+//! Every solution run produces a [`Record`] which is handed to a [`Reporter`].
+//! The text reporter prints each record as soon as it arrives, while the
+//! machine-readable reporters are meant to be parsed by other programs.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use common_v2::ParseError;
+use crossterm::execute;
+use crossterm::style::{Color, SetForegroundColor};
+use miette::GraphicalReportHandler;
+use serde::Serialize;
+
+use crate::bench::{Stats, Timings};
+use crate::memory;
+use crate::registry::Solution;
+
+/// Output formats supported by the runner.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable, colourised text.
+    #[default]
+    Text,
+    /// A single JSON array with one object per solution.
+    Json,
+    /// Comma-separated values with a header row.
+    Csv,
+}
+
+/// Where the input for a solution came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "input", rename_all = "lowercase")]
+pub enum InputSource {
+    /// The input embedded in the binary.
+    Embedded,
+    /// Input read from standard input.
+    Stdin,
+    /// Input read from a file, either given on the command line or found in
+    /// the input directory.
+    Custom {
+        #[serde(rename = "input_path")]
+        path: PathBuf,
+    },
+}
+
+impl InputSource {
+    fn kind(&self) -> &'static str {
+        match self {
+            InputSource::Embedded => "embedded",
+            InputSource::Stdin => "stdin",
+            InputSource::Custom { .. } => "custom",
+        }
+    }
+
+    pub fn path(&self) -> Option<&PathBuf> {
+        match self {
+            InputSource::Custom { path } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Short description of the source for use in tables.
+    fn label(&self) -> String {
+        match self {
+            InputSource::Embedded => "embedded".to_owned(),
+            InputSource::Stdin => "-".to_owned(),
+            InputSource::Custom { path } => path.to_string_lossy().into_owned(),
+        }
+    }
+}
+
+/// Why a solution did not produce any answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureKind {
+    /// The solution panicked.
+    Panic,
+    /// The solution ran past the time limit.
+    Timeout,
+    /// The input could not be read.
+    Input,
+    /// The solution rejected the input as malformed.
+    Parse,
+}
+
+impl FailureKind {
+    /// Short upper case label shown in front of the message.
+    pub fn label(self) -> &'static str {
+        match self {
+            FailureKind::Panic | FailureKind::Input | FailureKind::Parse => "FAILED",
+            FailureKind::Timeout => "TIMEOUT",
+        }
+    }
+}
+
+/// A solution that failed instead of producing answers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Failure {
+    pub kind: FailureKind,
+    pub message: String,
+    /// Source location of the panic, or the location in the input of a parse
+    /// error, as `file:line:column`, if known.
+    pub location: Option<String>,
+    /// Rendered snippet of the input highlighting where parsing failed.
+    #[serde(skip)]
+    pub snippet: Option<String>,
+}
+
+impl Failure {
+    pub fn timeout(limit: Duration) -> Self {
+        Failure {
+            kind: FailureKind::Timeout,
+            message: format!("took longer than {limit:.2?}"),
+            location: None,
+            snippet: None,
+        }
+    }
+
+    pub fn input(err: impl Display) -> Self {
+        Failure {
+            kind: FailureKind::Input,
+            message: err.to_string(),
+            location: None,
+            snippet: None,
+        }
+    }
+
+    /// Creates a failure for input the solution could not parse, including a
+    /// snippet of `input` with the offending part highlighted.
+    pub fn parse(err: ParseError, input: &[u8], source: &InputSource) -> Self {
+        let name = source.label();
+        let offset = err.span().offset().min(input.len());
+        let line = 1 + input[..offset].iter().filter(|&&b| b == b'\n').count();
+        let line_start = input[..offset]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let column = 1 + String::from_utf8_lossy(&input[line_start..offset])
+            .chars()
+            .count();
+
+        let message = err.message().to_owned();
+        let report = err.with_input(&name, input);
+        let mut snippet = String::new();
+        GraphicalReportHandler::new()
+            .render_report(&mut snippet, report.as_ref())
+            .ok();
+
+        Failure {
+            kind: FailureKind::Parse,
+            message,
+            location: Some(format!("{name}:{line}:{column}")),
+            snippet: Some(snippet),
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} (at {location})", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// The result of running a single solution.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    /// Answer for part 1, or `None` if the part was not solved.
+    pub part1: Option<String>,
+    /// Answer for part 2, or `None` if the part was not solved.
+    pub part2: Option<String>,
+    /// Error returned by part 1 instead of an answer.
+    pub part1_error: Option<String>,
+    /// Error returned by part 2 instead of an answer.
+    pub part2_error: Option<String>,
+    #[serde(flatten)]
+    pub input: InputSource,
+    pub timings: Option<Timings>,
+    /// Highest number of bytes allocated at once while solving, if measured.
+    #[serde(rename = "peak_memory_bytes")]
+    pub peak_memory: Option<u64>,
+    /// Set if the solution failed instead of producing answers.
+    pub failure: Option<Failure>,
+}
+
+impl Record {
+    /// Creates a record for the solution without any answers.
+    pub fn empty(solution: &Solution, year: u32, day: u32, input: InputSource) -> Self {
+        Record {
+            year,
+            day,
+            name: solution.title().to_string(),
+            part1: None,
+            part2: None,
+            part1_error: None,
+            part2_error: None,
+            input,
+            timings: None,
+            peak_memory: None,
+            failure: None,
+        }
+    }
+
+    /// Whether the solution failed as a whole or in one of the parts.
+    pub fn failed(&self) -> bool {
+        self.failure.is_some() || self.part1_error.is_some() || self.part2_error.is_some()
+    }
+}
+
+/// Receives the records of a run and outputs them.
+pub trait Reporter {
+    /// Called before the solutions for a year are run, but only when running
+    /// solutions for all years.
+    fn year_start(&mut self, _year: u32) {}
+
+    /// Called once for each solution that was run.
+    fn record(&mut self, record: &Record);
+
+    /// Called instead of [`Reporter::record`] when a single solution was run
+    /// with several inputs, with one record per input.
+    fn inputs(&mut self, records: &[Record]) {
+        for record in records {
+            self.record(record);
+        }
+    }
+
+    /// Called after all solutions for a year has been run.
+    fn year_end(&mut self, _year: u32, _records: &[Record]) {}
+
+    /// Called once after everything has been run.
+    fn finish(&mut self) {}
+}
+
+/// Creates a reporter for the given format.
+pub fn reporter(format: Format, timed: bool, memory: bool) -> Box<dyn Reporter> {
+    match format {
+        Format::Text => Box::new(TextReporter {
+            timed,
+            memory,
+            untimed_phases: 0,
+        }),
+        Format::Json => Box::new(JsonReporter::default()),
+        Format::Csv => Box::new(CsvReporter::default()),
+    }
+}
+
+/// Prints colourised, human readable text.
+struct TextReporter {
+    timed: bool,
+    memory: bool,
+    /// Days since the last summary that were only timed in total.
+    untimed_phases: usize,
+}
+
+impl TextReporter {
+    fn println_answer(part: u8, answer: &str) {
+        let mut lines = answer.lines();
+        if let Some(first_line) = lines.next() {
+            println!("({}) {}", part, first_line);
+        }
+        for line in lines {
+            println!("    {}", line);
+        }
+    }
+
+    fn println_error(part: u8, error: &str) {
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::Red)).ok();
+        println!("({part}) ERROR: {error}");
+        execute!(stdout, SetForegroundColor(Color::White)).ok();
+    }
+
+    fn println_failure(failure: &Failure) {
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::Red)).ok();
+        println!("{}: {failure}", failure.kind.label());
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+        if let Some(snippet) = &failure.snippet {
+            print!("{snippet}");
+        }
+    }
+
+    fn println_timings(timings: &Timings) {
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::DarkGrey)).ok();
+
+        if timings.runs == 1 {
+            for (phase, stats) in timings.phases() {
+                println!("    {phase:<6} {:>10.2?}", stats.median);
+            }
+        } else {
+            println!(
+                "    {:<6} {:>10} {:>10} {:>10}  ({} runs)",
+                "", "min", "median", "max", timings.runs
+            );
+            for (phase, stats) in timings.phases() {
+                println!(
+                    "    {phase:<6} {:>10.2?} {:>10.2?} {:>10.2?}",
+                    stats.min, stats.median, stats.max
+                );
+            }
+        }
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    }
+
+    /// Points out once that some days have no per-phase timings, as the
+    /// older solutions solve both parts in one go.
+    fn println_untimed_phases(&mut self) {
+        if self.untimed_phases == 0 {
+            return;
+        }
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::DarkGrey)).ok();
+        let days = match self.untimed_phases {
+            1 => "1 day is".to_owned(),
+            n => format!("{n} days are"),
+        };
+        println!("({days} only timed in total, parse and parts need common_v2)");
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+        self.untimed_phases = 0;
+    }
+
+    fn println_memory(peak_memory: u64) {
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::DarkGrey)).ok();
+        println!(
+            "    {:<6} {:>10}",
+            "memory",
+            memory::format_bytes(peak_memory)
+        );
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    }
+}
+
+impl Reporter for TextReporter {
+    fn year_start(&mut self, year: u32) {
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::Cyan)).ok();
+        println!("------ YEAR {} ------", year);
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    }
+
+    fn record(&mut self, record: &Record) {
+        let mut stdout = io::stdout();
+
+        execute!(stdout, SetForegroundColor(Color::Green)).ok();
+        println!("--- {} ---", record.name);
+
+        if let Some(failure) = &record.failure {
+            Self::println_failure(failure);
+            return;
+        }
+
+        execute!(stdout, SetForegroundColor(Color::White)).ok();
+        if let Some(part1) = &record.part1 {
+            Self::println_answer(1, part1);
+        }
+        if let Some(error) = &record.part1_error {
+            Self::println_error(1, error);
+        }
+        if let Some(part2) = &record.part2 {
+            Self::println_answer(2, part2);
+        }
+        if let Some(error) = &record.part2_error {
+            Self::println_error(2, error);
+        }
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+
+        if let Some(timings) = &record.timings {
+            Self::println_timings(timings);
+            if timings.parse.is_none() {
+                self.untimed_phases += 1;
+            }
+        }
+        if let Some(peak_memory) = record.peak_memory {
+            Self::println_memory(peak_memory);
+        }
+    }
+
+    fn inputs(&mut self, records: &[Record]) {
+        let Some(first) = records.first() else {
+            return;
+        };
+
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::Green)).ok();
+        println!("--- {} ---", first.name);
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+
+        // Every cell is a list of lines, as answers may span several lines.
+        let part1 = records
+            .iter()
+            .any(|record| record.part1.is_some() || record.part1_error.is_some());
+        let part2 = records
+            .iter()
+            .any(|record| record.part2.is_some() || record.part2_error.is_some());
+        let mut header = vec!["input"];
+        header.extend(part1.then_some("(1)"));
+        header.extend(part2.then_some("(2)"));
+        header.extend(self.timed.then_some("time"));
+        header.extend(self.memory.then_some("memory"));
+        let header: Vec<Vec<String>> = header
+            .into_iter()
+            .map(|cell| vec![cell.to_owned()])
+            .collect();
+
+        let rows: Vec<Vec<Vec<String>>> = records
+            .iter()
+            .map(|record| {
+                let mut row = vec![vec![record.input.label()]];
+                if record.failure.is_some() {
+                    return row;
+                }
+                let lines = |answer: &Option<String>, error: &Option<String>| match error {
+                    Some(error) => vec![format!("ERROR: {error}")],
+                    None => answer
+                        .iter()
+                        .flat_map(|a| a.lines())
+                        .map(String::from)
+                        .collect(),
+                };
+                if part1 {
+                    row.push(lines(&record.part1, &record.part1_error));
+                }
+                if part2 {
+                    row.push(lines(&record.part2, &record.part2_error));
+                }
+                if let Some(timings) = &record.timings {
+                    row.push(vec![format!("{:.2?}", timings.total.median)]);
+                }
+                if let Some(peak_memory) = record.peak_memory {
+                    row.push(vec![memory::format_bytes(peak_memory)]);
+                }
+                row
+            })
+            .collect();
+
+        let mut widths = vec![0; header.len()];
+        for row in rows.iter().chain([&header]) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = cell
+                    .iter()
+                    .map(|line| line.chars().count())
+                    .fold(*width, usize::max);
+            }
+        }
+
+        let println_row = |row: &[Vec<String>]| {
+            let height = row.iter().map(Vec::len).max().unwrap_or(0).max(1);
+            for i in 0..height {
+                let line: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| {
+                        format!("{:<width$}", cell.get(i).map_or("", String::as_str))
+                    })
+                    .collect();
+                println!("{}", line.join("  ").trim_end());
+            }
+        };
+
+        execute!(stdout, SetForegroundColor(Color::DarkGrey)).ok();
+        println_row(&header);
+        execute!(stdout, SetForegroundColor(Color::White)).ok();
+        for (record, row) in records.iter().zip(&rows) {
+            match &record.failure {
+                Some(failure) => {
+                    print!("{:<width$}  ", row[0][0], width = widths[0]);
+                    Self::println_failure(failure);
+                    execute!(stdout, SetForegroundColor(Color::White)).ok();
+                }
+                None => println_row(row),
+            }
+        }
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    }
+
+    fn year_end(&mut self, year: u32, records: &[Record]) {
+        if !self.timed {
+            return;
+        }
+
+        let year_total: Duration = records
+            .iter()
+            .filter_map(|record| record.timings.as_ref())
+            .map(|timings| timings.total.median)
+            .sum();
+
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(Color::Cyan)).ok();
+        println!("------ {year} total: {year_total:.2?} ------");
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+        self.println_untimed_phases();
+    }
+
+    fn finish(&mut self) {
+        // Days run on their own have no year summary.
+        self.println_untimed_phases();
+    }
+}
+
+/// Collects all records and prints them as a single JSON array at the end.
+#[derive(Default)]
+struct JsonReporter {
+    records: Vec<Record>,
+}
+
+impl Reporter for JsonReporter {
+    fn record(&mut self, record: &Record) {
+        self.records.push(record.clone());
+    }
+
+    fn finish(&mut self) {
+        let mut stdout = io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &self.records).expect("should write json");
+        writeln!(stdout).ok();
+    }
+}
+
+/// Prints one CSV row per record. Durations are given in nanoseconds.
+#[derive(Default)]
+struct CsvReporter {
+    header_written: bool,
+}
+
+impl CsvReporter {
+    const PHASES: [&str; 4] = ["parse", "part1", "part2", "total"];
+
+    fn header() -> Vec<String> {
+        let mut header: Vec<String> = [
+            "year",
+            "day",
+            "name",
+            "part1",
+            "part2",
+            "input",
+            "input_path",
+            "part1_error",
+            "part2_error",
+            "failure",
+            "peak_memory_bytes",
+            "runs",
+        ]
+        .map(String::from)
+        .into();
+
+        for phase in Self::PHASES {
+            for stat in ["min", "median", "max"] {
+                header.push(format!("{phase}_{stat}_ns"));
+            }
+        }
+
+        header
+    }
+
+    fn row(record: &Record) -> Vec<String> {
+        let mut row = vec![
+            record.year.to_string(),
+            record.day.to_string(),
+            record.name.clone(),
+            record.part1.clone().unwrap_or_default(),
+            record.part2.clone().unwrap_or_default(),
+            record.input.kind().to_string(),
+            record
+                .input
+                .path()
+                .map(|path| path.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            record.part1_error.clone().unwrap_or_default(),
+            record.part2_error.clone().unwrap_or_default(),
+            record
+                .failure
+                .as_ref()
+                .map(|failure| failure.to_string())
+                .unwrap_or_default(),
+            record
+                .peak_memory
+                .map(|bytes| bytes.to_string())
+                .unwrap_or_default(),
+        ];
+
+        let Some(timings) = &record.timings else {
+            row.resize(row.len() + 1 + 3 * Self::PHASES.len(), String::new());
+            return row;
+        };
+
+        row.push(timings.runs.to_string());
+        for stats in [
+            timings.parse,
+            timings.part1,
+            timings.part2,
+            Some(timings.total),
+        ] {
+            match stats {
+                Some(Stats { min, median, max }) => {
+                    row.extend([min, median, max].map(|d| d.as_nanos().to_string()))
+                }
+                None => row.extend([String::new(), String::new(), String::new()]),
+            }
+        }
+
+        row
+    }
+}
+
+impl Reporter for CsvReporter {
+    fn record(&mut self, record: &Record) {
+        if !self.header_written {
+            println!("{}", csv_line(&Self::header()));
+            self.header_written = true;
+        }
+        println!("{}", csv_line(&Self::row(record)));
+    }
+}
+
+/// Joins the fields into a single CSV line, quoting fields as needed.
+fn csv_line(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> Record {
+        Record {
+            year: 2022,
+            day: 10,
+            name: "Day 10: Cathode-Ray Tube".to_string(),
+            part1: Some("12540".to_string()),
+            part2: Some("#..#\n.##.".to_string()),
+            part1_error: None,
+            part2_error: None,
+            input: InputSource::Embedded,
+            timings: None,
+            peak_memory: None,
+            failure: None,
+        }
+    }
+
+    #[test]
+    fn csv_quotes_fields() {
+        let fields = ["a".to_string(), "b,c".to_string(), "d\"e\nf".to_string()];
+        assert_eq!(csv_line(&fields), "a,\"b,c\",\"d\"\"e\nf\"");
+    }
+
+    #[test]
+    fn csv_row_matches_header() {
+        assert_eq!(
+            CsvReporter::row(&record()).len(),
+            CsvReporter::header().len()
+        );
+    }
+
+    #[test]
+    fn json_record() {
+        let mut record = record();
+        record.part1 = None;
+        record.input = InputSource::Custom {
+            path: PathBuf::from("my_input"),
+        };
+        let json = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(json["year"], 2022);
+        assert_eq!(json["day"], 10);
+        assert!(json["part1"].is_null());
+        assert_eq!(json["part2"], "#..#\n.##.");
+        assert!(json["part1_error"].is_null());
+        assert_eq!(json["input"], "custom");
+        assert_eq!(json["input_path"], "my_input");
+        assert!(json["timings"].is_null());
+        assert!(json["failure"].is_null());
+    }
+
+    #[test]
+    fn json_failure() {
+        let mut record = record();
+        record.failure = Some(Failure::timeout(Duration::from_secs(10)));
+        let json = serde_json::to_value(&record).unwrap();
+
+        assert_eq!(json["failure"]["kind"], "timeout");
+        assert_eq!(json["failure"]["message"], "took longer than 10.00s");
+        assert!(json["failure"]["location"].is_null());
+    }
+
+    #[test]
+    fn parse_failure_location() {
+        let input = b"L68\nX30\n";
+        let err = ParseError::at(input, &input[4..5], "expected direction to be L or R");
+        let failure = Failure::parse(err, input, &InputSource::Embedded);
+
+        assert_eq!(failure.kind, FailureKind::Parse);
+        assert_eq!(failure.message, "expected direction to be L or R");
+        assert_eq!(failure.location.as_deref(), Some("embedded:2:1"));
+        assert!(failure.snippet.unwrap().contains("X30"));
+    }
+}
@@ -0,0 +1,631 @@
+//! Scaffolding of new days, used by the `new` subcommand.
+//!
+//! A new day is a module generated from `day_template` in the year crate,
+//! registered in the year's `SOLUTIONS` array in day order. Years written
+//! against the first version of the common crate get a day from the legacy
+//! template. Years that do not exist yet get a new crate, which is added to
+//! the workspace and the registry.
+//!
+//! Every file is prepared before anything is written, so a day that can not
+//! be added leaves the repository untouched. Existing days are never
+//! overwritten.
+
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../day_template/mod.rs");
+const LEGACY_TEMPLATE: &str = include_str!("../day_template/legacy.rs");
+
+/// First year of Advent of Code.
+const FIRST_YEAR: u32 = 2015;
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// There is no puzzle for the given year and day.
+    InvalidDay { year: u32, day: u32 },
+    /// The day already has a module or a solution registered.
+    Exists { year: u32, day: u32, reason: String },
+    /// A file that should be edited does not look as expected.
+    Unrecognised { path: PathBuf, reason: String },
+    /// A file could not be read or written.
+    Io { path: PathBuf, err: io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay { year, day } => {
+                write!(f, "there is no puzzle for {year} day {day}")
+            }
+            ScaffoldError::Exists { year, day, reason } => {
+                write!(f, "{year} day {day} already exists, {reason}")
+            }
+            ScaffoldError::Unrecognised { path, reason } => {
+                write!(f, "failed to edit '{}': {reason}", path.to_string_lossy())
+            }
+            ScaffoldError::Io { path, err } => {
+                write!(f, "failed to access '{}': {err}", path.to_string_lossy())
+            }
+        }
+    }
+}
+
+/// Whether a file is created or changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Create,
+    Edit,
+}
+
+/// A file to write, relative to the root of the repository.
+#[derive(Debug)]
+struct FileChange {
+    path: PathBuf,
+    contents: String,
+    change: Change,
+}
+
+/// Number of puzzles in the given year.
+fn days_in(year: u32) -> u32 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Adds a day generated from the template to the repository at `root`,
+/// creating the year crate if needed. Returns the files created or changed.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<(PathBuf, Change)>, ScaffoldError> {
+    if year < FIRST_YEAR || !(1..=days_in(year)).contains(&day) {
+        return Err(ScaffoldError::InvalidDay { year, day });
+    }
+
+    let changes = plan(root, year, day)?;
+    for change in &changes {
+        let path = root.join(&change.path);
+        let io_err = |err| ScaffoldError::Io {
+            path: path.clone(),
+            err,
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_err)?;
+        }
+        fs::write(&path, &change.contents).map_err(io_err)?;
+    }
+
+    Ok(changes
+        .into_iter()
+        .map(|change| (change.path, change.change))
+        .collect())
+}
+
+/// Prepares the files to write for the new day.
+fn plan(root: &Path, year: u32, day: u32) -> Result<Vec<FileChange>, ScaffoldError> {
+    let year_dir = PathBuf::from(year.to_string());
+    let day_dir = year_dir.join("src").join(format!("day{day}"));
+    let lib_path = year_dir.join("src").join("lib.rs");
+
+    if root.join(&day_dir).exists() {
+        return Err(ScaffoldError::Exists {
+            year,
+            day,
+            reason: format!("'{}' is in the way", day_dir.to_string_lossy()),
+        });
+    }
+
+    let mut changes = vec![FileChange {
+        path: day_dir.join("input"),
+        contents: String::new(),
+        change: Change::Create,
+    }];
+
+    let legacy = if root.join(&year_dir).exists() {
+        let lib = read(root, &lib_path)?;
+        let (lib, version) = register_day(&lib, day).map_err(|err| match err {
+            RegisterError::Exists(reason) => ScaffoldError::Exists { year, day, reason },
+            RegisterError::Unrecognised(reason) => ScaffoldError::Unrecognised {
+                path: lib_path.clone(),
+                reason,
+            },
+        })?;
+        changes.push(FileChange {
+            path: lib_path,
+            contents: lib,
+            change: Change::Edit,
+        });
+        version == Version::Legacy
+    } else {
+        let (lib, _) = register_day(&new_lib(year), day).expect("new lib.rs should be valid");
+        changes.extend([
+            FileChange {
+                path: year_dir.join("Cargo.toml"),
+                contents: year_manifest(year),
+                change: Change::Create,
+            },
+            FileChange {
+                path: year_dir.join("build.rs"),
+                contents: "fn main() {\n    common::examples::generate();\n}\n".to_owned(),
+                change: Change::Create,
+            },
+            FileChange {
+                path: lib_path,
+                contents: lib,
+                change: Change::Create,
+            },
+        ]);
+
+        for path in ["Cargo.toml", "src/registry.rs"].map(PathBuf::from) {
+            let contents = read(root, &path)?;
+            let edit = if path.ends_with("Cargo.toml") {
+                add_to_workspace(&contents, year)
+            } else {
+                add_to_registry(&contents, year)
+            };
+            let contents = edit.map_err(|reason| ScaffoldError::Unrecognised {
+                path: path.clone(),
+                reason,
+            })?;
+            changes.push(FileChange {
+                path,
+                contents,
+                change: Change::Edit,
+            });
+        }
+        false
+    };
+
+    changes.insert(
+        0,
+        FileChange {
+            path: day_dir.join("mod.rs"),
+            contents: day_module(year, day, legacy),
+            change: Change::Create,
+        },
+    );
+
+    Ok(changes)
+}
+
+/// The module of a new day, from the template for the version of the common
+/// crate the year uses.
+fn day_module(year: u32, day: u32, legacy: bool) -> String {
+    let module = if legacy {
+        LEGACY_TEMPLATE
+            .replace("    year: 0,", &format!("    year: {year},"))
+            .replace("    day: 0,", &format!("    day: {day},"))
+    } else {
+        TEMPLATE
+            .replace(
+                "const YEAR: u32 = 0;",
+                &format!("const YEAR: u32 = {year};"),
+            )
+            .replace("const DAY: u32 = 0;", &format!("const DAY: u32 = {day};"))
+    };
+    module.replace("Day X", &format!("Day {day}"))
+}
+
+fn read(root: &Path, path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(root.join(path)).map_err(|err| ScaffoldError::Io {
+        path: path.to_owned(),
+        err,
+    })
+}
+
+#[derive(Debug)]
+enum RegisterError {
+    Exists(String),
+    Unrecognised(String),
+}
+
+/// Version of the common crate the solutions of a year are written against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Version {
+    /// `common::Solution`, registered as `dayN::SOLUTION`.
+    Legacy,
+    /// `common_v2::Solver`, registered as `DynSolver::new::<dayN::Solution>()`.
+    V2,
+}
+
+/// Adds the module of the day to the `lib.rs` of a year and registers it in
+/// the `SOLUTIONS` array, keeping the days in order. Returns the new
+/// `lib.rs` and the version of the common crate the year uses.
+fn register_day(lib: &str, day: u32) -> Result<(String, Version), RegisterError> {
+    let module = format!("day{day}");
+    let mut lines: Vec<String> = lib.lines().map(str::to_owned).collect();
+
+    if lines
+        .iter()
+        .any(|line| line.trim() == format!("pub mod {module};"))
+    {
+        return Err(RegisterError::Exists(format!(
+            "'{module}' is declared in lib.rs"
+        )));
+    }
+
+    // Find the array before adding the module, which shifts the lines.
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const SOLUTIONS"))
+        .ok_or_else(|| RegisterError::Unrecognised("no SOLUTIONS array".to_owned()))?;
+    let version = if lines[start].contains("DynSolver") {
+        Version::V2
+    } else if lines[start].contains("common::Solution") {
+        Version::Legacy
+    } else {
+        return Err(RegisterError::Unrecognised(
+            "SOLUTIONS holds neither DynSolver nor common::Solution".to_owned(),
+        ));
+    };
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.contains("];"))
+            .ok_or_else(|| {
+                RegisterError::Unrecognised("SOLUTIONS array is not closed".to_owned())
+            })?;
+
+    let array = lines[start..=end].join("\n");
+    let (header, entries) = array
+        .split_once("= &[")
+        .and_then(|(header, rest)| Some((header, rest.rsplit_once(']')?.0)))
+        .ok_or_else(|| RegisterError::Unrecognised("SOLUTIONS is not an array".to_owned()))?;
+    let mut entries: Vec<&str> = entries
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect();
+    let entry = match version {
+        Version::Legacy => format!("{module}::SOLUTION"),
+        Version::V2 => format!("DynSolver::new::<{module}::Solution>()"),
+    };
+    if entries.contains(&entry.as_str()) {
+        return Err(RegisterError::Exists(format!("SOLUTIONS has '{entry}'")));
+    }
+    let at = entries
+        .iter()
+        .position(|entry| entry_day(entry).is_some_and(|d| d > day))
+        .unwrap_or(entries.len());
+    entries.insert(at, &entry);
+
+    let array: Vec<String> = [format!("{header}= &[")]
+        .into_iter()
+        .chain(entries.iter().map(|entry| format!("    {entry},")))
+        .chain(["];".to_owned()])
+        .collect();
+    lines.splice(start..=end, array);
+
+    // Keep the module declarations sorted by name.
+    let modules: Vec<usize> = (0..start)
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let declaration = format!("pub mod {module};");
+    let at = modules
+        .iter()
+        .copied()
+        .find(|&i| lines[i].trim_end_matches(';') > declaration.trim_end_matches(';'))
+        .or(modules.last().map(|&i| i + 1))
+        .unwrap_or(0);
+    lines.insert(at, declaration);
+    if modules.is_empty() && lines.get(at + 1).is_some_and(|line| !line.is_empty()) {
+        lines.insert(at + 1, String::new());
+    }
+
+    Ok((lines.join("\n") + "\n", version))
+}
+
+/// Day of the module an entry of a `SOLUTIONS` array refers to.
+fn entry_day(entry: &str) -> Option<u32> {
+    let (_, rest) = entry.split_once("day")?;
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// `lib.rs` of a year with no solutions.
+fn new_lib(year: u32) -> String {
+    format!(
+        r#"use common_v2::DynSolver;
+
+pub const SOLUTIONS: &[DynSolver] = &[];
+
+const _: () = common_v2::check_solutions({year}, SOLUTIONS);
+
+#[cfg(test)]
+mod examples {{
+    #[allow(unused_imports)]
+    use common_v2::{{example_file, fuzz_file}};
+
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}}
+"#
+    )
+}
+
+fn year_manifest(year: u32) -> String {
+    format!(
+        r#"[package]
+name = "solutions_{year}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+common_v2 = {{path = "../common_v2"}}
+
+[build-dependencies]
+common = {{path = "../common"}}
+"#
+    )
+}
+
+/// Adds the crate of a year to the workspace members and dependencies of
+/// the root `Cargo.toml`.
+fn add_to_workspace(manifest: &str, year: u32) -> Result<String, String> {
+    let mut lines: Vec<String> = manifest.lines().map(str::to_owned).collect();
+
+    let last = lines
+        .iter()
+        .rposition(|line| line.starts_with("solutions_"))
+        .ok_or("no solutions dependencies")?;
+    lines.insert(
+        last + 1,
+        format!("solutions_{year} = {{path = \"{year}\"}}"),
+    );
+
+    let members = lines
+        .iter_mut()
+        .find(|line| line.starts_with("members = ["))
+        .ok_or("no workspace members")?;
+    *members = format!(
+        "members = [{}]",
+        add_year(members, year, |y| format!("\"{y}\""))?
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the solutions of a year to the registry.
+fn add_to_registry(registry: &str, year: u32) -> Result<String, String> {
+    let mut lines: Vec<String> = registry.lines().map(str::to_owned).collect();
+    let alias = format!("s{}", year % 100);
+
+    let last = lines
+        .iter()
+        .rposition(|line| line.starts_with("use solutions_"))
+        .ok_or("no solutions imports")?;
+    lines.insert(last + 1, format!("use solutions_{year} as {alias};"));
+
+    let all_years = lines
+        .iter_mut()
+        .find(|line| line.starts_with("pub const ALL_YEARS"))
+        .ok_or("no ALL_YEARS")?;
+    let years = add_year(all_years, year, |y| y.to_string())?;
+    let count = years.split(", ").count();
+    *all_years = format!("pub const ALL_YEARS: [u32; {count}] = [{years}];");
+
+    let function = lines
+        .iter()
+        .position(|line| line.starts_with("pub fn solutions_for_year"))
+        .ok_or("no solutions_for_year")?;
+    let fallback = function
+        + lines[function..]
+            .iter()
+            .position(|line| line.trim() == "_ => return None,")
+            .ok_or("no fallback arm in solutions_for_year")?;
+    let indent = &lines[fallback][..lines[fallback].len() - lines[fallback].trim_start().len()];
+    let arm = format!(
+        "{indent}{year} => {alias}::SOLUTIONS.iter().copied().map(Solution::Parts).collect(),"
+    );
+    lines.insert(fallback, arm);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds `year` to the sorted list of years between the brackets of `line`,
+/// returning the new list.
+fn add_year(line: &str, year: u32, format: impl Fn(u32) -> String) -> Result<String, String> {
+    let list = line
+        .rsplit_once('[')
+        .and_then(|(_, rest)| rest.split_once(']'))
+        .map(|(list, _)| list)
+        .ok_or_else(|| format!("no list of years in '{line}'"))?;
+
+    let mut years: Vec<u32> = list
+        .split(',')
+        .map(|year| year.trim().trim_matches('"'))
+        .filter(|year| !year.is_empty())
+        .map(|year| year.parse().map_err(|_| format!("'{year}' is not a year")))
+        .collect::<Result<_, _>>()?;
+    if years.contains(&year) {
+        return Err(format!("{year} is already in '{line}'"));
+    }
+    years.push(year);
+    years.sort_unstable();
+
+    Ok(years.into_iter().map(format).collect::<Vec<_>>().join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "\
+pub mod day1;
+mod day_ex1;
+
+use common_v2::DynSolver;
+
+pub const SOLUTIONS: &[DynSolver] = &[DynSolver::new::<day1::Solution>()];
+";
+
+    #[test]
+    fn register() {
+        let (lib, version) = register_day(LIB, 2).unwrap();
+        assert_eq!(version, Version::V2);
+        assert!(lib.starts_with("pub mod day1;\npub mod day2;\nmod day_ex1;\n"));
+        assert!(lib.ends_with(
+            "pub const SOLUTIONS: &[DynSolver] = &[\n    \
+             DynSolver::new::<day1::Solution>(),\n    \
+             DynSolver::new::<day2::Solution>(),\n];\n"
+        ));
+
+        let (lib, _) = register_day(&new_lib(2026), 10).unwrap();
+        assert!(lib.starts_with("pub mod day10;\n\nuse common_v2::DynSolver;\n"));
+        assert!(lib.contains("&[\n    DynSolver::new::<day10::Solution>(),\n];"));
+    }
+
+    #[test]
+    fn register_existing() {
+        assert!(matches!(
+            register_day(LIB, 1),
+            Err(RegisterError::Exists(_))
+        ));
+        let lib = LIB.replace("pub mod day1;\n", "");
+        assert!(matches!(
+            register_day(&lib, 1),
+            Err(RegisterError::Exists(_))
+        ));
+        let lib = "pub const SOLUTIONS: &[common::Solution] = &[day1::SOLUTION];";
+        assert!(matches!(
+            register_day(lib, 1),
+            Err(RegisterError::Exists(_))
+        ));
+    }
+
+    #[test]
+    fn register_legacy() {
+        // A year that skipped a day.
+        let lib = "\
+pub mod day1;
+pub mod day2;
+pub mod day20;
+pub mod day22;
+
+pub const SOLUTIONS: &[common::Solution] = &[day1::SOLUTION, day20::SOLUTION, day22::SOLUTION];
+
+const _: () = common::check_solutions(2024, SOLUTIONS);
+";
+        let (lib, version) = register_day(lib, 21).unwrap();
+        assert_eq!(version, Version::Legacy);
+        assert_eq!(
+            lib,
+            "\
+pub mod day1;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+
+pub const SOLUTIONS: &[common::Solution] = &[
+    day1::SOLUTION,
+    day20::SOLUTION,
+    day21::SOLUTION,
+    day22::SOLUTION,
+];
+
+const _: () = common::check_solutions(2024, SOLUTIONS);
+"
+        );
+
+        let module = day_module(2024, 21, true);
+        assert!(module.contains("    year: 2024,\n    day: 21,\n    name: \"Day 21: Template\","));
+        let module = day_module(2025, 3, false);
+        assert!(module.contains("const YEAR: u32 = 2025;\n    const DAY: u32 = 3;"));
+    }
+
+    #[test]
+    fn add_years() {
+        let line = "pub const ALL_YEARS: [u32; 2] = [2015, 2025];";
+        assert_eq!(
+            add_year(line, 2016, |y| y.to_string()).unwrap(),
+            "2015, 2016, 2025"
+        );
+        let line = r#"members = ["2015", "2025"]"#;
+        assert_eq!(
+            add_year(line, 2026, |y| format!("\"{y}\"")).unwrap(),
+            r#""2015", "2025", "2026""#
+        );
+    }
+
+    const MANIFEST: &str = r#"[dependencies]
+clap = {version = "4", features = ["derive", "cargo", "env", "help"]}
+solutions_2015 = {path = "2015"}
+solutions_2025 = {path = "2025"}
+
+[workspace]
+members = ["2015", "2025"]
+"#;
+
+    const REGISTRY: &str = "\
+use solutions_2015 as s15;
+use solutions_2025 as s25;
+
+pub const ALL_YEARS: [u32; 2] = [2015, 2025];
+
+pub fn solutions_for_year(year: u32) -> Option<Vec<Solution>> {
+    let mut solutions: Vec<Solution> = match year {
+        2015 => s15::SOLUTIONS.iter().map(Solution::Legacy).collect(),
+        2025 => s25::SOLUTIONS.iter().copied().map(Solution::Parts).collect(),
+        _ => return None,
+    };
+    solutions.sort_by_key(Solution::day);
+    Some(solutions)
+}
+";
+
+    #[test]
+    fn add_new_year() {
+        assert_eq!(
+            add_to_workspace(MANIFEST, 2026).unwrap(),
+            MANIFEST
+                .replace(
+                    "solutions_2025 = {path = \"2025\"}\n",
+                    "solutions_2025 = {path = \"2025\"}\nsolutions_2026 = {path = \"2026\"}\n"
+                )
+                .replace(r#""2025"]"#, r#""2025", "2026"]"#)
+        );
+
+        let registry = add_to_registry(REGISTRY, 2026).unwrap();
+        assert!(registry.contains("use solutions_2025 as s25;\nuse solutions_2026 as s26;\n"));
+        assert!(registry.contains("pub const ALL_YEARS: [u32; 3] = [2015, 2025, 2026];"));
+        assert!(registry.contains(
+            "        2026 => s26::SOLUTIONS.iter().copied().map(Solution::Parts).collect(),\n        \
+             _ => return None,"
+        ));
+    }
+
+    #[test]
+    fn add_year_to_unrecognised_files() {
+        // Already added.
+        assert!(add_to_workspace(MANIFEST, 2025).is_err());
+        assert!(add_to_registry(REGISTRY, 2015).is_err());
+
+        // Missing anchors.
+        let registry = REGISTRY.replace("        _ => return None,\n", "");
+        assert!(add_to_registry(&registry, 2026).is_err());
+        let registry = REGISTRY.replace("pub fn solutions_for_year", "fn solutions_for_year");
+        assert!(add_to_registry(&registry, 2026).is_err());
+        let manifest = MANIFEST.replace("[workspace]\nmembers = [\"2015\", \"2025\"]\n", "");
+        assert!(add_to_workspace(&manifest, 2026).is_err());
+    }
+
+    #[test]
+    fn refuse_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let day_dir = root.join("2025").join("src").join("day3");
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(root.join("2025").join("src").join("lib.rs"), LIB).unwrap();
+
+        let result = new_day(&root, 2025, 3);
+        let lib = fs::read_to_string(root.join("2025").join("src").join("lib.rs")).unwrap();
+        let day_files = fs::read_dir(&day_dir).unwrap().count();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(matches!(result, Err(ScaffoldError::Exists { .. })));
+        assert_eq!(lib, LIB);
+        assert_eq!(day_files, 0);
+        assert!(matches!(
+            new_day(&root, 2025, 13),
+            Err(ScaffoldError::InvalidDay { .. })
+        ));
+    }
+}
@@ -0,0 +1,86 @@
+//! Live status line showing the progress reported by running solutions
+//! through [`common_v2::progress`].
+//!
+//! The line is drawn on standard error and cleared again before the result
+//! of the solution is printed. It is only shown for text output in an
+//! interactive terminal. With `--jobs`, the line shows the solution that
+//! started last, even if the update came from another one.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_v2::progress::{self, Update};
+use crossterm::cursor::MoveToColumn;
+use crossterm::queue;
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType};
+
+/// State of the status line, `None` when no status line is installed.
+static LINE: Mutex<Option<Line>> = Mutex::new(None);
+
+#[derive(Default)]
+struct Line {
+    /// The solution that started last, like `2022 day 19`.
+    label: String,
+    /// Whether the line currently shows anything.
+    shown: bool,
+}
+
+/// Shows the progress of solutions in a status line if both standard output
+/// and standard error are interactive terminals.
+pub fn install() {
+    if !io::stdout().is_terminal() || !io::stderr().is_terminal() {
+        return;
+    }
+    *lock() = Some(Line::default());
+    progress::set_reporter(Some(Arc::new(render)));
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Line>> {
+    LINE.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Marks the start of a solution, whose name labels the updates.
+pub fn start(year: u32, day: u32) {
+    if let Some(line) = lock().as_mut() {
+        line.label = format!("{year} day {day:>2}");
+    }
+}
+
+/// Clears the status line, so the result of a solution can be printed.
+pub fn clear() {
+    if let Some(line) = lock().as_mut().filter(|line| line.shown) {
+        let mut stderr = io::stderr().lock();
+        queue!(stderr, MoveToColumn(0), Clear(ClearType::CurrentLine)).ok();
+        stderr.flush().ok();
+        line.shown = false;
+    }
+}
+
+fn render(update: &Update) {
+    let mut guard = lock();
+    let Some(line) = guard.as_mut() else {
+        return;
+    };
+
+    let mut text = format!("{}: {update}", line.label);
+    // A line wider than the terminal wraps and can not be cleared.
+    let width = terminal::size().map_or(80, |(width, _)| width as usize);
+    if let Some((end, _)) = text.char_indices().nth(width.saturating_sub(1)) {
+        text.truncate(end);
+    }
+
+    let mut stderr = io::stderr().lock();
+    queue!(
+        stderr,
+        MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+        SetForegroundColor(Color::DarkGrey),
+        Print(text),
+        ResetColor
+    )
+    .ok();
+    stderr.flush().ok();
+    line.shown = true;
+}
@@ -0,0 +1,418 @@
+//! Verification of solutions against a manifest of known answers.
+//!
+//! The manifest is a TOML file with one table per day keyed by year and day,
+//! each holding the expected answer for `part1` and `part2`:
+//!
+//! ```toml
+//! [2022.10]
+//! part1 = "12540"
+//! part2 = '''
+//! ####.####..
+//! #....#.....'''
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+use common::ocr;
+use crossterm::execute;
+use crossterm::style::{Color, SetForegroundColor};
+use serde::Deserialize;
+
+use crate::report::{FailureKind, Record, Reporter};
+
+/// A single expected answer. Integers are accepted for convenience and are
+/// compared by their decimal representation.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Text(String),
+    Int(i64),
+}
+
+impl Answer {
+    fn into_string(self) -> String {
+        match self {
+            Answer::Text(s) => s,
+            Answer::Int(i) => i.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Expected answers for each part of each day.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<(u32, u32), [Option<String>; 2]>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    InvalidKey(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "{err}"),
+            AnswersError::Parse(err) => write!(f, "{err}"),
+            AnswersError::InvalidKey(key) => {
+                write!(f, "'{key}' is not a valid year or day")
+            }
+        }
+    }
+}
+
+impl Answers {
+    /// Reads and parses the answers manifest at `path`.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let content = std::fs::read_to_string(path).map_err(AnswersError::Io)?;
+        Self::parse(&content)
+    }
+
+    /// Parses the content of an answers manifest.
+    pub fn parse(content: &str) -> Result<Self, AnswersError> {
+        let years: BTreeMap<String, BTreeMap<String, DayAnswers>> =
+            toml::from_str(content).map_err(AnswersError::Parse)?;
+
+        let parse_key = |key: &str| -> Result<u32, AnswersError> {
+            key.parse()
+                .map_err(|_| AnswersError::InvalidKey(key.to_owned()))
+        };
+
+        let mut days = BTreeMap::new();
+        for (year, year_answers) in years {
+            let year = parse_key(&year)?;
+            for (day, day_answers) in year_answers {
+                let day = parse_key(&day)?;
+                days.insert(
+                    (year, day),
+                    [
+                        day_answers.part1.map(Answer::into_string),
+                        day_answers.part2.map(Answer::into_string),
+                    ],
+                );
+            }
+        }
+
+        Ok(Answers { days })
+    }
+
+    /// Returns the days with known answers, in year and day order.
+    pub fn days(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.days.keys().copied()
+    }
+
+    /// Returns the expected answer for the given part (1 or 2), if known.
+    pub fn get(&self, year: u32, day: u32, part: u8) -> Option<&str> {
+        let answers = self.days.get(&(year, day))?;
+        answers[usize::from(part) - 1].as_deref()
+    }
+}
+
+/// Outcome of verifying a single part. Answers drawn in block letters are
+/// compared by the letters they show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    Missing,
+    /// The solution failed before giving an answer.
+    Failed(FailureKind),
+    /// The part returned an error instead of an answer.
+    Error(String),
+    /// The part was not solved.
+    Skipped,
+}
+
+impl Status {
+    fn check(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (_, None) => Status::Skipped,
+            (Some(expected), Some(actual))
+                if ocr::normalise(expected) == ocr::normalise(actual) =>
+            {
+                Status::Pass
+            }
+            (Some(expected), Some(_)) => Status::Fail {
+                expected: expected.to_owned(),
+            },
+            (None, Some(_)) => Status::Missing,
+        }
+    }
+
+    fn label(&self) -> (&'static str, Color) {
+        match self {
+            Status::Pass => ("pass", Color::Green),
+            Status::Fail { .. } => ("FAIL", Color::Red),
+            Status::Missing => ("missing", Color::Yellow),
+            Status::Failed(kind) => (kind.label(), Color::Red),
+            Status::Error(_) => ("ERROR", Color::Red),
+            Status::Skipped => ("-", Color::DarkGrey),
+        }
+    }
+}
+
+/// Reporter that compares every record against the known answers and
+/// prints a summary table.
+pub struct Verifier {
+    answers: Answers,
+    /// Year and day being verified, or `None` for all of them.
+    year: Option<u32>,
+    day: Option<u32>,
+    /// Part that is solved, or `None` for both.
+    part: Option<u8>,
+    /// Days a record was given for.
+    seen: BTreeSet<(u32, u32)>,
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    /// Days with known answers that were not solved.
+    unsolved: usize,
+}
+
+impl Verifier {
+    pub fn new(answers: Answers, year: Option<u32>, day: Option<u32>, part: Option<u8>) -> Self {
+        Verifier {
+            answers,
+            year,
+            day,
+            part,
+            seen: BTreeSet::new(),
+            passed: 0,
+            failed: 0,
+            missing: 0,
+            unsolved: 0,
+        }
+    }
+
+    /// Returns `true` if no answer differed from the expected one, and every
+    /// day with known answers was solved.
+    pub fn success(&self) -> bool {
+        self.failed == 0 && self.unsolved == 0
+    }
+
+    /// Days being verified that have known answers but no record, like days
+    /// with no registered solution.
+    fn unsolved_days(&self) -> Vec<(u32, u32)> {
+        self.answers
+            .days()
+            .filter(|(year, day)| {
+                self.year.is_none_or(|y| y == *year) && self.day.is_none_or(|d| d == *day)
+            })
+            .filter(|key| !self.seen.contains(key))
+            .collect()
+    }
+
+    fn print_status(status: &Status) {
+        let (label, colour) = status.label();
+        let mut stdout = io::stdout();
+        execute!(stdout, SetForegroundColor(colour)).ok();
+        print!("{label:<8}");
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    }
+
+    fn println_mismatch(part: u8, expected: &str, actual: &str) {
+        let println_answer = |heading: &str, answer: &str| {
+            let mut lines = answer.lines();
+            println!("      {heading:<16} {}", lines.next().unwrap_or_default());
+            for line in lines {
+                println!("      {:<16} {line}", "");
+            }
+        };
+
+        println_answer(&format!("(part {part}) expected"), expected);
+        println_answer("got", actual);
+    }
+}
+
+impl Reporter for Verifier {
+    fn record(&mut self, record: &Record) {
+        self.seen.insert((record.year, record.day));
+
+        // A solution that failed or timed out gives no answers, so every part
+        // it should have solved counts as failed.
+        if let Some(failure) = &record.failure {
+            print!("{} day {:>2}  ", record.year, record.day);
+            for part in 1..=2 {
+                if self.part.is_none_or(|p| p == part) {
+                    Self::print_status(&Status::Failed(failure.kind));
+                    self.failed += 1;
+                } else {
+                    Self::print_status(&Status::Skipped);
+                }
+            }
+            println!(" {}", record.name);
+
+            let mut stdout = io::stdout();
+            execute!(stdout, SetForegroundColor(Color::Red)).ok();
+            println!("      {failure}");
+            execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+            if let Some(snippet) = &failure.snippet {
+                print!("{snippet}");
+            }
+            return;
+        }
+
+        let actual = [record.part1.as_deref(), record.part2.as_deref()];
+        let errors = [&record.part1_error, &record.part2_error];
+        let statuses: Vec<Status> = (1..)
+            .zip(actual)
+            .zip(errors)
+            .map(|((part, actual), error)| match error {
+                Some(error) => Status::Error(error.clone()),
+                None => {
+                    let expected = self.answers.get(record.year, record.day, part);
+                    Status::check(expected, actual)
+                }
+            })
+            .collect();
+
+        print!("{} day {:>2}  ", record.year, record.day);
+        for status in statuses.iter() {
+            Self::print_status(status);
+            match status {
+                Status::Pass => self.passed += 1,
+                Status::Fail { .. } | Status::Failed(_) | Status::Error(_) => self.failed += 1,
+                Status::Missing => self.missing += 1,
+                Status::Skipped => {}
+            }
+        }
+        println!(" {}", record.name);
+
+        for ((part, actual), status) in (1..).zip(actual).zip(&statuses) {
+            match (status, actual) {
+                (Status::Fail { expected }, Some(actual)) => {
+                    Self::println_mismatch(part, expected, actual)
+                }
+                (Status::Error(error), _) => {
+                    let mut stdout = io::stdout();
+                    execute!(stdout, SetForegroundColor(Color::Red)).ok();
+                    println!("      (part {part}) error: {error}");
+                    execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        // Answers for days that were never run would otherwise go unnoticed,
+        // for example when a solution is no longer registered.
+        for (year, day) in self.unsolved_days() {
+            print!("{year} day {day:>2}  ");
+            for part in 1..=2 {
+                if self.part.is_none_or(|p| p == part)
+                    && self.answers.get(year, day, part).is_some()
+                {
+                    Self::print_status(&Status::Missing);
+                    self.missing += 1;
+                } else {
+                    Self::print_status(&Status::Skipped);
+                }
+            }
+            println!(" no solution found");
+            self.unsolved += 1;
+        }
+
+        let mut stdout = io::stdout();
+        let colour = if self.success() {
+            Color::Green
+        } else {
+            Color::Red
+        };
+        execute!(stdout, SetForegroundColor(colour)).ok();
+        println!(
+            "------ {} passed, {} failed, {} missing ------",
+            self.passed, self.failed, self.missing
+        );
+        execute!(stdout, SetForegroundColor(Color::Reset)).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[2022.10]
+part1 = "12540"
+part2 = '''
+#..#
+.##.'''
+
+[2025.1]
+part1 = 1078
+"#;
+
+    #[test]
+    fn parse_manifest() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        assert_eq!(answers.get(2022, 10, 1), Some("12540"));
+        assert_eq!(answers.get(2022, 10, 2), Some("#..#\n.##."));
+        assert_eq!(answers.get(2025, 1, 1), Some("1078"));
+        assert_eq!(answers.get(2025, 1, 2), None);
+        assert_eq!(answers.get(2015, 1, 1), None);
+    }
+
+    #[test]
+    fn parse_invalid_key() {
+        let result = Answers::parse("[2022.ten]\npart1 = \"1\"\n");
+        assert!(matches!(result, Err(AnswersError::InvalidKey(key)) if key == "ten"));
+    }
+
+    #[test]
+    fn check_status() {
+        assert_eq!(Status::check(Some("1"), Some("1")), Status::Pass);
+        assert_eq!(
+            Status::check(Some("1"), Some("2")),
+            Status::Fail {
+                expected: "1".to_owned()
+            }
+        );
+        assert_eq!(Status::check(None, Some("2")), Status::Missing);
+        assert_eq!(Status::check(Some("1"), None), Status::Skipped);
+
+        let art = "\
+####.####
+#....#...
+###..###.
+#....#...
+#....#...
+#....####";
+        assert_eq!(Status::check(Some("FE"), Some(art)), Status::Pass);
+    }
+
+    #[test]
+    fn unsolved_days() {
+        let answers = || Answers::parse(MANIFEST).unwrap();
+
+        let mut verifier = Verifier::new(answers(), None, None, None);
+        verifier.seen.insert((2022, 10));
+        assert_eq!(verifier.unsolved_days(), [(2025, 1)]);
+
+        let verifier = Verifier::new(answers(), Some(2022), None, None);
+        assert_eq!(verifier.unsolved_days(), [(2022, 10)]);
+        let verifier = Verifier::new(answers(), Some(2022), Some(9), None);
+        assert!(verifier.unsolved_days().is_empty());
+    }
+
+    #[test]
+    fn repository_manifest_is_valid() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml");
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(2015, 1, 1), Some("232"));
+    }
+}
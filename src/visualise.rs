@@ -0,0 +1,210 @@
+//! Playback of the frames drawn by solutions through [`common_v2::visual`],
+//! shown once the answers are printed when running with `--visualise`.
+//!
+//! The frames are played on the alternate screen, so the answers are still
+//! there when the playback is done. Space pauses, the arrow keys step one
+//! frame at a time, `+` and `-` change the speed and `q` quits.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use common_v2::visual;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{
+    self, Clear, ClearType, DisableLineWrap, EnableLineWrap, EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+
+/// Most frames kept, so a solution drawing too often does not run out of
+/// memory. Later frames are dropped.
+const MAX_FRAMES: usize = 10_000;
+
+/// Time each frame is shown for when starting the playback.
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+const MIN_DELAY: Duration = Duration::from_millis(5);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+static RECORDING: Mutex<Recording> = Mutex::new(Recording {
+    frames: Vec::new(),
+    dropped: 0,
+});
+
+struct Recording {
+    frames: Vec<String>,
+    /// Number of frames past [`MAX_FRAMES`].
+    dropped: usize,
+}
+
+fn lock() -> MutexGuard<'static, Recording> {
+    RECORDING.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Starts recording the frames drawn by solutions.
+pub fn install() {
+    visual::set_recorder(Some(Arc::new(|frame| {
+        let mut recording = lock();
+        if recording.frames.len() < MAX_FRAMES {
+            recording.frames.push(frame);
+        } else {
+            recording.dropped += 1;
+        }
+    })));
+}
+
+/// Stops recording and plays back the frames recorded so far, returning
+/// when the user quits.
+pub fn play() {
+    visual::set_recorder(None);
+    let Recording { frames, dropped } = std::mem::replace(
+        &mut *lock(),
+        Recording {
+            frames: Vec::new(),
+            dropped: 0,
+        },
+    );
+
+    if frames.is_empty() {
+        eprintln!("no frames to show, the solution does not draw any.");
+        return;
+    }
+    if !io::stdout().is_terminal() {
+        eprintln!("frames can only be shown in a terminal.");
+        return;
+    }
+    if dropped > 0 {
+        eprintln!("only showing the first {MAX_FRAMES} frames, {dropped} more were dropped.");
+    }
+
+    let mut player = Player {
+        frames,
+        current: 0,
+        playing: true,
+        delay: DEFAULT_DELAY,
+    };
+    if let Err(err) = player.run() {
+        eprintln!("failed to show frames: {err}");
+    }
+}
+
+struct Player {
+    frames: Vec<String>,
+    current: usize,
+    playing: bool,
+    delay: Duration,
+}
+
+impl Player {
+    fn run(&mut self) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        terminal::enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen, Hide, DisableLineWrap)?;
+        let result = self.handle_keys(&mut stdout);
+        // Restore the terminal even if drawing failed.
+        execute!(stdout, EnableLineWrap, Show, LeaveAlternateScreen).ok();
+        terminal::disable_raw_mode().ok();
+        result
+    }
+
+    fn last(&self) -> usize {
+        self.frames.len() - 1
+    }
+
+    fn handle_keys(&mut self, stdout: &mut impl Write) -> io::Result<()> {
+        // Keys pressed while the solution was running are not meant for the
+        // playback.
+        while event::poll(Duration::ZERO)? {
+            event::read()?;
+        }
+
+        loop {
+            self.draw(stdout)?;
+
+            // Wait for a key, or until the next frame is due.
+            if self.playing && !event::poll(self.delay)? {
+                if self.current < self.last() {
+                    self.current += 1;
+                } else {
+                    self.playing = false;
+                }
+                continue;
+            }
+            let Event::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            else {
+                continue;
+            };
+
+            match code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+                KeyCode::Char(' ') => {
+                    // Playing from the last frame starts over.
+                    if !self.playing && self.current == self.last() {
+                        self.current = 0;
+                    }
+                    self.playing = !self.playing;
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.playing = false;
+                    self.current = (self.current + 1).min(self.last());
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.playing = false;
+                    self.current = self.current.saturating_sub(1);
+                }
+                KeyCode::Home => self.current = 0,
+                KeyCode::End => self.current = self.last(),
+                KeyCode::Char('+' | '=') => self.delay = (self.delay / 2).max(MIN_DELAY),
+                KeyCode::Char('-') => self.delay = (self.delay * 2).min(MAX_DELAY),
+                _ => {}
+            }
+        }
+    }
+
+    fn draw(&self, stdout: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        // The last line is kept for the controls.
+        let rows = height.saturating_sub(1);
+
+        let frame = &self.frames[self.current];
+        let mut y = 0;
+        for line in frame.lines().take(rows as usize) {
+            queue!(
+                stdout,
+                MoveTo(0, y),
+                Print(line),
+                ResetColor,
+                Clear(ClearType::UntilNewLine)
+            )?;
+            y += 1;
+        }
+        queue!(stdout, MoveTo(0, y), Clear(ClearType::FromCursorDown))?;
+
+        let state = if self.playing { "playing" } else { "paused" };
+        let fps = 1.0 / self.delay.as_secs_f64();
+        let mut controls = format!(
+            "frame {}/{} {state} at {fps:.0} fps | space: play/pause, ←/→: step, +/-: speed, q: quit",
+            self.current + 1,
+            self.frames.len(),
+        );
+        if let Some((end, _)) = controls.char_indices().nth(width as usize) {
+            controls.truncate(end);
+        }
+        queue!(
+            stdout,
+            MoveTo(0, rows),
+            SetForegroundColor(Color::DarkGrey),
+            Print(controls),
+            ResetColor
+        )?;
+        stdout.flush()
+    }
+}
@@ -0,0 +1,54 @@
+//! Running solutions with a time limit.
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::parallel::STACK_SIZE;
+
+/// Runs `f` on a new thread and waits at most `timeout` for it to finish,
+/// returning `None` if it did not.
+///
+/// Threads can not be stopped from the outside, so a solution that overruns
+/// keeps running in the background until the runner exits, competing with
+/// the solutions after it for CPU time and adding to their `--memory` peaks.
+pub fn run<T, F>(timeout: Duration, f: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (tx, rx) = mpsc::sync_channel(1);
+    thread::Builder::new()
+        .name("solution".to_owned())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // The receiver is gone if the time ran out.
+            tx.send(f()).ok();
+        })
+        .expect("should be able to spawn solution thread");
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => panic!("solution thread ended without a result"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finishes_in_time() {
+        assert_eq!(run(Duration::from_secs(10), || 42), Some(42));
+    }
+
+    #[test]
+    fn times_out() {
+        let result = run(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_secs(1));
+            42
+        });
+        assert_eq!(result, None);
+    }
+}